@@ -94,6 +94,8 @@ TRAITS = {
             "signum",
             "div_euclid",
             "rem_euclid",
+            "to_bits",
+            "from_bits",
             # Has unstable trait bound
             "to_int_unchecked<Int>",
             # Deprecated
@@ -132,7 +134,11 @@ TRAITS = {
         example_implementor="i32",
         core_fns=signed_core,
         std_fns=[],
-        ignores=set(),
+        ignores={
+            # Implemented on Number trait
+            "saturating_abs",
+            "wrapping_abs",
+        },
         replacements={
             "_unsigned(self, rhs: u32)": "_unsigned(self, rhs: Self::Unsigned)",
             "unsigned_abs(self) -> u32": "unsigned_abs(self) -> Self::Unsigned",
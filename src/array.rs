@@ -1,12 +1,19 @@
 use core::{
+    any::type_name,
     borrow::{Borrow, BorrowMut},
+    mem::MaybeUninit,
     ops::{
         Bound, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
         RangeToInclusive,
     },
+    slice::Iter,
 };
 
-use crate::primitive::Primitive;
+use crate::{
+    num::{Float, Number},
+    primitive::Primitive,
+    slice::{self, Slice},
+};
 
 macro_rules! array_trait {
     (($($bounds:tt +)*); ($($alloc_bounds:tt +)*) $impl:tt) => {
@@ -55,13 +62,112 @@ array_trait!(
     +) {
         const N: usize;
 
+        /// An array of the same length with items of type `U`.
+        type Mapped<U>: Array<Item = U>;
+
+        /// Creates an array of uninitialized items.
+        ///
+        /// See [`assume_init`] for extracting the items once initialized.
+        fn uninit() -> Self::Mapped<MaybeUninit<Self::Item>>;
+
+        /// See [`core::array::from_fn`].
+        fn from_fn<F>(f: F) -> Self
+        where
+            F: FnMut(usize) -> Self::Item;
+
+        /// Copies `slice` into an array, returning `None` if its length is not
+        /// [`N`](Array::N).
+        fn try_from_slice(slice: &[Self::Item]) -> Option<Self>
+        where
+            Self::Item: Copy;
+
+        /// Collects exactly [`N`](Array::N) items from `iter` into an array,
+        /// returning `None` if it yields fewer or more items.
+        ///
+        /// At most `N + 1` items are consumed from `iter`.
+        #[cfg(feature = "alloc")]
+        fn try_from_iter<I>(iter: I) -> Option<Self>
+        where
+            I: IntoIterator<Item = Self::Item>;
+
+        /// Moves the items into a [`Vec`](alloc::vec::Vec).
+        #[cfg(feature = "alloc")]
+        fn into_vec(self) -> alloc::vec::Vec<Self::Item>;
+
+        /// Returns the name of the item type.
+        ///
+        /// See [`core::any::type_name`].
+        fn item_type_name() -> &'static str;
+
         fn as_slice(&self) -> &[Self::Item];
         fn as_mut_slice(&mut self) -> &mut [Self::Item];
-        fn map<F, U>(self, f: F) -> impl Array<Item = U>
+
+        /// See [`slice::get`].
+        fn get(&self, i: usize) -> Option<&Self::Item>;
+
+        /// See [`slice::get_mut`].
+        fn get_mut(&mut self, i: usize) -> Option<&mut Self::Item>;
+
+        /// See [`slice::swap`].
+        fn swap(&mut self, a: usize, b: usize);
+
+        fn map<F, U>(self, f: F) -> Self::Mapped<U>
         where
             F: FnMut(Self::Item) -> U;
         fn each_ref(&self) -> impl Array<Item = &Self::Item>;
         fn each_mut(&mut self) -> impl Array<Item = &mut Self::Item>;
+
+        /// Returns the dot product of `self` and `other`, i.e. the sum of the
+        /// products of their items.
+        fn dot(&self, other: &Self) -> Self::Item
+        where
+            Self::Item: Number;
+
+        /// Returns the smallest item, or `None` if the array is empty.
+        ///
+        /// Items are compared with [`Number::num_min`], so NaN items are
+        /// skipped. The result is only NaN if all items are NaN.
+        fn min_element(&self) -> Option<Self::Item>
+        where
+            Self::Item: Number;
+
+        /// Returns the largest item, or `None` if the array is empty.
+        ///
+        /// Items are compared with [`Number::num_max`], so NaN items are
+        /// skipped. The result is only NaN if all items are NaN.
+        fn max_element(&self) -> Option<Self::Item>
+        where
+            Self::Item: Number;
+
+        /// Restricts every item to the interval `[lo, hi]`.
+        ///
+        /// See [`Number::num_clamp`], which also describes when this panics.
+        fn clamp_each(&mut self, lo: Self::Item, hi: Self::Item)
+        where
+            Self::Item: Number;
+
+        /// Returns `true` if any item is NaN.
+        fn any_nan(&self) -> bool
+        where
+            Self::Item: Float;
+
+        /// Returns `true` if all items are finite, i.e. neither infinite nor
+        /// NaN.
+        fn all_finite(&self) -> bool
+        where
+            Self::Item: Float;
+
+        /// See [`Slice::kahan_sum`].
+        fn kahan_sum(&self) -> Self::Item
+        where
+            Self::Item: Float;
+
+        /// Converts each item to `U`, saturating at the bounds of `U`.
+        ///
+        /// See [`Number::saturating_cast`].
+        fn cast_each<U: Number>(self) -> Self::Mapped<U>
+        where
+            Self::Item: Number;
     }
 );
 
@@ -69,6 +175,44 @@ impl<const N: usize, T> Primitive for [T; N] {}
 impl<const N: usize, T> Array for [T; N] {
     const N: usize = N;
 
+    type Mapped<U> = [U; N];
+
+    fn uninit() -> Self::Mapped<MaybeUninit<Self::Item>> {
+        [const { MaybeUninit::uninit() }; N]
+    }
+
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item,
+    {
+        core::array::from_fn(f)
+    }
+
+    fn try_from_slice(slice: &[Self::Item]) -> Option<Self>
+    where
+        Self::Item: Copy,
+    {
+        slice.try_into().ok()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let vec: alloc::vec::Vec<T> = iter.into_iter().take(N + 1).collect();
+        vec.try_into().ok()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn into_vec(self) -> alloc::vec::Vec<Self::Item> {
+        self.into()
+    }
+
+    fn item_type_name() -> &'static str {
+        type_name::<T>()
+    }
+
     fn as_slice(&self) -> &[Self::Item] {
         self.as_slice()
     }
@@ -77,7 +221,19 @@ impl<const N: usize, T> Array for [T; N] {
         self.as_mut_slice()
     }
 
-    fn map<F, U>(self, f: F) -> impl Array<Item = U>
+    fn get(&self, i: usize) -> Option<&Self::Item> {
+        self.as_slice().get(i)
+    }
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut Self::Item> {
+        self.as_mut_slice().get_mut(i)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b)
+    }
+
+    fn map<F, U>(self, f: F) -> Self::Mapped<U>
     where
         F: FnMut(Self::Item) -> U,
     {
@@ -91,6 +247,139 @@ impl<const N: usize, T> Array for [T; N] {
     fn each_mut(&mut self) -> impl Array<Item = &mut Self::Item> {
         self.each_mut()
     }
+
+    fn dot(&self, other: &Self) -> Self::Item
+    where
+        Self::Item: Number,
+    {
+        slice::dot(self, other)
+    }
+
+    fn min_element(&self) -> Option<Self::Item>
+    where
+        Self::Item: Number,
+    {
+        self.iter().copied().reduce(T::num_min)
+    }
+
+    fn max_element(&self) -> Option<Self::Item>
+    where
+        Self::Item: Number,
+    {
+        self.iter().copied().reduce(T::num_max)
+    }
+
+    fn clamp_each(&mut self, lo: Self::Item, hi: Self::Item)
+    where
+        Self::Item: Number,
+    {
+        for v in self {
+            *v = v.num_clamp(lo, hi);
+        }
+    }
+
+    fn any_nan(&self) -> bool
+    where
+        Self::Item: Float,
+    {
+        self.iter().any(|v| v.is_nan())
+    }
+
+    fn all_finite(&self) -> bool
+    where
+        Self::Item: Float,
+    {
+        self.iter().all(|v| v.is_finite())
+    }
+
+    fn kahan_sum(&self) -> Self::Item
+    where
+        Self::Item: Float,
+    {
+        Slice::kahan_sum(self.as_slice())
+    }
+
+    fn cast_each<U: Number>(self) -> Self::Mapped<U>
+    where
+        Self::Item: Number,
+    {
+        self.map(T::saturating_cast)
+    }
+}
+
+/// Read-only access to arrays and references to arrays.
+///
+/// Unlike [`Array`], this is also implemented for `&[T; N]` and
+/// `&mut [T; N]`, as it doesn't have any methods consuming the array.
+pub trait BorrowedArray {
+    type Item;
+
+    const N: usize;
+
+    fn as_slice(&self) -> &[Self::Item];
+    fn iter(&self) -> Iter<'_, Self::Item>;
+}
+
+impl<const N: usize, T> BorrowedArray for [T; N] {
+    type Item = T;
+
+    const N: usize = N;
+
+    fn as_slice(&self) -> &[Self::Item] {
+        self
+    }
+
+    fn iter(&self) -> Iter<'_, Self::Item> {
+        self[..].iter()
+    }
+}
+
+impl<const N: usize, T> BorrowedArray for &[T; N] {
+    type Item = T;
+
+    const N: usize = N;
+
+    fn as_slice(&self) -> &[Self::Item] {
+        *self
+    }
+
+    fn iter(&self) -> Iter<'_, Self::Item> {
+        self[..].iter()
+    }
+}
+
+impl<const N: usize, T> BorrowedArray for &mut [T; N] {
+    type Item = T;
+
+    const N: usize = N;
+
+    fn as_slice(&self) -> &[Self::Item] {
+        &**self
+    }
+
+    fn iter(&self) -> Iter<'_, Self::Item> {
+        self[..].iter()
+    }
+}
+
+/// Extracts the values from an array of [`MaybeUninit`] items.
+///
+/// # Safety
+///
+/// All items must be initialized. See [`MaybeUninit::assume_init`].
+pub unsafe fn assume_init<A, T>(array: A) -> A::Mapped<T>
+where
+    A: Array<Item = MaybeUninit<T>>,
+{
+    array.map(|v| unsafe { v.assume_init() })
+}
+
+/// Transposes a matrix stored as an array of `R` rows with `C` columns each.
+pub fn transpose<T, const R: usize, const C: usize>(m: [[T; C]; R]) -> [[T; R]; C]
+where
+    T: Copy,
+{
+    core::array::from_fn(|c| core::array::from_fn(|r| m[r][c]))
 }
 
 #[cfg(test)]
@@ -115,4 +404,257 @@ mod test {
 
         assert_eq!(to_vec([123, 456]), alloc::vec![123, 456]);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_into_vec() {
+        fn into_vec<T: Array>(v: T) -> alloc::vec::Vec<T::Item> {
+            v.into_vec()
+        }
+
+        assert_eq!(into_vec([1u8, 2, 3]), alloc::vec![1, 2, 3]);
+        assert_eq!(into_vec::<[u8; 0]>([]), alloc::vec![]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_from_iter() {
+        fn try_from_iter<T: Array>(iter: impl IntoIterator<Item = T::Item>) -> Option<T> {
+            T::try_from_iter(iter)
+        }
+
+        assert_eq!(try_from_iter::<[u8; 3]>(1..=3), Some([1, 2, 3]));
+        assert_eq!(try_from_iter::<[u8; 3]>(1..=2), None);
+        assert_eq!(try_from_iter::<[u8; 3]>(1..=4), None);
+        assert_eq!(try_from_iter::<[u8; 3]>(1..), None);
+        assert_eq!(try_from_iter::<[u8; 0]>([]), Some([]));
+    }
+
+    #[test]
+    fn test_uninit() {
+        fn fill<T: Array<Item = MaybeUninit<u32>>>(arr: &mut T) {
+            for (i, v) in arr.as_mut_slice().iter_mut().enumerate() {
+                v.write(i as u32);
+            }
+        }
+
+        let mut arr = <[u32; 4]>::uninit();
+        fill(&mut arr);
+        assert_eq!(unsafe { assume_init(arr) }, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_borrowed_array() {
+        fn len<T: BorrowedArray>(v: T) -> usize {
+            assert_eq!(v.as_slice().len(), T::N);
+            assert_eq!(v.iter().count(), T::N);
+            T::N
+        }
+
+        let mut arr = [1, 2, 3];
+        let empty: &[u8; 0] = &[];
+        assert_eq!(len(arr), 3);
+        assert_eq!(len(empty), 0);
+
+        let shared: &[i32; 3] = &arr;
+        assert_eq!(len(shared), 3);
+        let unique: &mut [i32; 3] = &mut arr;
+        assert_eq!(len(unique), 3);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        fn squares<T: Array<Item = usize>>() -> T {
+            T::from_fn(|i| i * i)
+        }
+
+        assert_eq!(squares::<[usize; 4]>(), [0, 1, 4, 9]);
+        assert_eq!(squares::<[usize; 0]>(), []);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        fn try_from_slice<T: Array>(slice: &[T::Item]) -> Option<T>
+        where
+            T::Item: Copy,
+        {
+            T::try_from_slice(slice)
+        }
+
+        assert_eq!(try_from_slice::<[u8; 3]>(&[1, 2, 3]), Some([1, 2, 3]));
+        assert_eq!(try_from_slice::<[u8; 3]>(&[1, 2]), None);
+        assert_eq!(try_from_slice::<[u8; 0]>(&[]), Some([]));
+    }
+
+    #[test]
+    fn test_dot() {
+        fn dot<T: Array>(a: &T, b: &T) -> T::Item
+        where
+            T::Item: Number,
+        {
+            a.dot(b)
+        }
+
+        assert_eq!(dot(&[1, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(dot(&[0.5f64, -1.0], &[4.0, 2.0]), 0.0);
+        assert_eq!(dot::<[u8; 0]>(&[], &[]), 0);
+    }
+
+    #[test]
+    fn test_item_type_name() {
+        fn item_type_name<T: Array>(_: &T) -> &'static str {
+            T::item_type_name()
+        }
+
+        assert!(item_type_name(&[0u8; 4]).contains("u8"));
+        assert!(item_type_name(&[[0u16; 2]; 0]).contains("[u16; 2]"));
+    }
+
+    #[test]
+    fn test_get() {
+        fn get<T: Array>(v: &T, i: usize) -> Option<&T::Item> {
+            v.get(i)
+        }
+
+        fn get_mut<T: Array>(v: &mut T, i: usize) -> Option<&mut T::Item> {
+            v.get_mut(i)
+        }
+
+        let mut v = [1u8, 2, 3];
+        assert_eq!(get(&v, 0), Some(&1));
+        assert_eq!(get(&v, 2), Some(&3));
+        assert_eq!(get(&v, 3), None);
+        *get_mut(&mut v, 1).unwrap() = 5;
+        assert_eq!(get_mut(&mut v, 3), None);
+        assert_eq!(v, [1, 5, 3]);
+    }
+
+    #[test]
+    fn test_swap() {
+        fn swap<T: Array>(v: &mut T, a: usize, b: usize) {
+            v.swap(a, b);
+        }
+
+        let mut v = [1u8, 2, 3];
+        swap(&mut v, 0, 2);
+        assert_eq!(v, [3, 2, 1]);
+        swap(&mut v, 1, 1);
+        assert_eq!(v, [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
+        Array::swap(&mut [1u8, 2, 3], 0, 3);
+    }
+
+    #[test]
+    fn test_any_nan_all_finite() {
+        fn any_nan<T: Array>(v: &T) -> bool
+        where
+            T::Item: Float,
+        {
+            v.any_nan()
+        }
+
+        fn all_finite<T: Array>(v: &T) -> bool
+        where
+            T::Item: Float,
+        {
+            v.all_finite()
+        }
+
+        assert!(any_nan(&[1.0, f64::NAN, 3.0]));
+        assert!(!any_nan(&[1.0f64, 2.0]));
+        assert!(!any_nan(&[f32::INFINITY]));
+        assert!(!any_nan::<[f32; 0]>(&[]));
+        assert!(all_finite(&[1.0f64, 2.0]));
+        assert!(!all_finite(&[1.0, f64::NAN]));
+        assert!(!all_finite(&[f32::NEG_INFINITY]));
+        assert!(all_finite::<[f32; 0]>(&[]));
+    }
+
+    #[test]
+    fn test_min_max_element() {
+        fn min_element<T: Array>(v: &T) -> Option<T::Item>
+        where
+            T::Item: Number,
+        {
+            v.min_element()
+        }
+
+        fn max_element<T: Array>(v: &T) -> Option<T::Item>
+        where
+            T::Item: Number,
+        {
+            v.max_element()
+        }
+
+        assert_eq!(min_element(&[3, -1, 7, 2]), Some(-1));
+        assert_eq!(max_element(&[3, -1, 7, 2]), Some(7));
+        assert_eq!(min_element(&[5u8]), Some(5));
+        assert_eq!(max_element::<[u8; 0]>(&[]), None);
+        assert_eq!(min_element::<[u8; 0]>(&[]), None);
+
+        assert_eq!(min_element(&[2.0, f64::NAN, -1.0]), Some(-1.0));
+        assert_eq!(max_element(&[f32::NAN, 2.0, -1.0]), Some(2.0));
+        assert!(max_element(&[f32::NAN, f32::NAN]).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_clamp_each() {
+        fn clamp_each<T: Array>(v: &mut T, lo: T::Item, hi: T::Item)
+        where
+            T::Item: Number,
+        {
+            v.clamp_each(lo, hi);
+        }
+
+        let mut arr = [-5, 0, 10];
+        clamp_each(&mut arr, 0, 5);
+        assert_eq!(arr, [0, 0, 5]);
+
+        let mut arr = [-1.5, 0.25, 2.0];
+        clamp_each(&mut arr, -1.0, 1.0);
+        assert_eq!(arr, [-1.0, 0.25, 1.0]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(transpose(m), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(transpose(transpose(m)), m);
+        assert_eq!(transpose([[7u8]]), [[7]]);
+        assert_eq!(transpose::<u8, 0, 2>([]), [[], []]);
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        fn kahan_sum<T: Array>(v: &T) -> T::Item
+        where
+            T::Item: Float,
+        {
+            v.kahan_sum()
+        }
+
+        assert_eq!(kahan_sum(&[1e16, 1.0, -1e16]), 1.0);
+        assert_eq!(kahan_sum(&[0.5f32, 0.25]), 0.75);
+    }
+
+    #[test]
+    fn test_cast_each() {
+        fn cast_each<T: Array, U: Number>(v: T) -> T::Mapped<U>
+        where
+            T::Item: Number,
+        {
+            v.cast_each()
+        }
+
+        let pixels: [u8; 3] = cast_each([-1.0f32, 128.0, 300.0]);
+        assert_eq!(pixels, [0, 128, 255]);
+        let wide: [f64; 2] = cast_each([u8::MAX, 0]);
+        assert_eq!(wide, [255.0, 0.0]);
+        let narrow: [i8; 3] = cast_each([-1000i32, 5, 1000]);
+        assert_eq!(narrow, [i8::MIN, 5, i8::MAX]);
+    }
 }
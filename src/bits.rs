@@ -0,0 +1,180 @@
+//! Reading and writing unsigned integers of arbitrary bit widths.
+
+use core::mem::size_of;
+
+use crate::{bytes::BufferFullError, num::Unsigned};
+
+/// Reads unsigned integers of arbitrary bit widths from a byte slice, starting
+/// with the most significant bit of each byte.
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Returns the number of bits that haven't been read yet.
+    pub fn remaining_bits(&self) -> usize {
+        self.buf.len() * 8 - self.pos
+    }
+
+    /// Reads an `n` bit value, returning `None` without consuming anything if
+    /// there are not enough bits left or if `n` is larger than the number of
+    /// bits in `T`.
+    pub fn read_bits<T: Unsigned>(&mut self, n: u32) -> Option<T> {
+        if n as usize > 8 * size_of::<T>() || n as usize > self.remaining_bits() {
+            return None;
+        }
+        let mut value = T::ZERO;
+        for _ in 0..n {
+            let bit = (self.buf[self.pos / 8] >> (7 - self.pos % 8)) & 1;
+            value <<= T::ONE;
+            value |= T::from(bit);
+            self.pos += 1;
+        }
+        Some(value)
+    }
+}
+
+/// Writes unsigned integers of arbitrary bit widths to a byte slice, starting
+/// with the most significant bit of each byte.
+///
+/// Bits are stored in the buffer as soon as they are written, and the unused
+/// bits of a partially written byte are set to zero.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Returns the number of bits that have been written so far.
+    pub fn written_bits(&self) -> usize {
+        self.pos
+    }
+
+    /// Writes the low `n` bits of `value`, ignoring any higher bits, and
+    /// writes nothing if there is not enough space left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the number of bits in `T`.
+    pub fn write_bits<T: Unsigned>(&mut self, value: T, n: u32) -> Result<(), BufferFullError> {
+        assert!(
+            n as usize <= 8 * size_of::<T>(),
+            "cannot write more bits than the value has"
+        );
+        if n as usize > self.buf.len() * 8 - self.pos {
+            return Err(BufferFullError);
+        }
+        for i in (0..n).rev() {
+            let mut bit = value;
+            bit >>= T::from(i as u8);
+            bit &= T::ONE;
+            let mask = 0x80 >> (self.pos % 8);
+            let byte = &mut self.buf[self.pos / 8];
+            if bit == T::ZERO {
+                *byte &= !mask;
+            } else {
+                *byte |= mask;
+            }
+            self.pos += 1;
+        }
+        if !self.pos.is_multiple_of(8) {
+            self.buf[self.pos / 8] &= !(0xff >> (self.pos % 8));
+        }
+        Ok(())
+    }
+
+    /// Returns the bytes that have been written, including a final partially
+    /// written byte.
+    pub fn finish(self) -> &'a [u8] {
+        &self.buf[..self.pos.div_ceil(8)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_bits() {
+        let byte = 0b101_10011;
+        let buf = [byte];
+        let mut reader = BitReader::new(&buf);
+        let high: u8 = reader.read_bits(3).unwrap();
+        let low: u8 = reader.read_bits(5).unwrap();
+        assert_eq!((high, low), (0b101, 0b10011));
+        assert_eq!((high << 5) | low, byte);
+        assert_eq!(reader.read_bits::<u8>(1), None);
+
+        let mut reader = BitReader::new(&[0xab, 0xcd, 0xef]);
+        assert_eq!(reader.read_bits::<u16>(4), Some(0xa));
+        assert_eq!(reader.read_bits::<u16>(12), Some(0xbcd));
+        assert_eq!(reader.read_bits::<u8>(9), None);
+        assert_eq!(reader.remaining_bits(), 8);
+        assert_eq!(reader.read_bits::<u64>(9), None);
+        assert_eq!(reader.read_bits::<u32>(0), Some(0));
+        assert_eq!(reader.read_bits::<u128>(8), Some(0xef));
+        assert_eq!(reader.remaining_bits(), 0);
+
+        let mut reader = BitReader::new(&[0xff; 3]);
+        assert_eq!(reader.read_bits::<u8>(1), Some(1));
+        assert_eq!(reader.read_bits::<u16>(16), Some(0xffff));
+    }
+
+    #[test]
+    fn test_write_bits() {
+        let mut buf = [0xaa; 3];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write_bits(0b101u8, 3).unwrap();
+        writer.write_bits(0b10011u8, 5).unwrap();
+        assert_eq!(writer.written_bits(), 8);
+        // Only the low 4 bits are written, crossing into the next byte.
+        writer.write_bits(0xfff5u16, 4).unwrap();
+        assert_eq!(writer.finish(), [0b101_10011, 0b0101_0000]);
+        assert_eq!(buf[2], 0xaa);
+
+        let mut buf = [0; 2];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write_bits(0u8, 0).unwrap();
+        writer.write_bits(0x1ffu32, 9).unwrap();
+        assert_eq!(writer.write_bits(0u8, 8), Err(BufferFullError));
+        writer.write_bits(0u64, 7).unwrap();
+        assert_eq!(writer.write_bits(1u8, 1), Err(BufferFullError));
+        assert_eq!(writer.finish(), [0xff, 0x80]);
+    }
+
+    #[test]
+    fn test_write_read_roundtrip() {
+        let fields: [(u32, u32); 6] = [(1, 1), (5, 3), (0x1234, 13), (0, 2), (0x7f, 7), (9, 5)];
+        let mut buf = [0; 4];
+        let mut writer = BitWriter::new(&mut buf);
+        for (value, n) in fields {
+            writer.write_bits(value, n).unwrap();
+        }
+        assert_eq!(writer.finish().len(), 4);
+        assert_eq!(buf, [0b1101_1001, 0b0001_1010, 0b0001_1111, 0b1101_0010]);
+
+        let mut reader = BitReader::new(&buf);
+        for (value, n) in fields {
+            assert_eq!(reader.read_bits::<u32>(n), Some(value));
+        }
+        assert_eq!(reader.remaining_bits(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_too_many_bits() {
+        let mut buf = [0; 4];
+        let _ = BitWriter::new(&mut buf).write_bits(0u8, 9);
+    }
+}
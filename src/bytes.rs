@@ -0,0 +1,264 @@
+//! Reading and writing numbers from and to byte buffers.
+
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use crate::{array::Array, num::Number};
+
+/// A byte order used by [`Reader`] and [`Writer`].
+pub trait Endian {
+    /// Creates a number from its representation as a byte array in this byte
+    /// order.
+    fn from_bytes<T: Number>(bytes: T::ByteArray) -> T;
+
+    /// Returns the representation of a number as a byte array in this byte
+    /// order.
+    fn to_bytes<T: Number>(v: T) -> T::ByteArray;
+}
+
+/// Big-endian byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigEndian;
+
+/// Little-endian byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LittleEndian;
+
+/// The native byte order of the target platform.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NativeEndian;
+
+impl Endian for BigEndian {
+    fn from_bytes<T: Number>(bytes: T::ByteArray) -> T {
+        T::from_be_bytes(bytes)
+    }
+
+    fn to_bytes<T: Number>(v: T) -> T::ByteArray {
+        v.to_be_bytes()
+    }
+}
+
+impl Endian for LittleEndian {
+    fn from_bytes<T: Number>(bytes: T::ByteArray) -> T {
+        T::from_le_bytes(bytes)
+    }
+
+    fn to_bytes<T: Number>(v: T) -> T::ByteArray {
+        v.to_le_bytes()
+    }
+}
+
+impl Endian for NativeEndian {
+    fn from_bytes<T: Number>(bytes: T::ByteArray) -> T {
+        T::from_ne_bytes(bytes)
+    }
+
+    fn to_bytes<T: Number>(v: T) -> T::ByteArray {
+        v.to_ne_bytes()
+    }
+}
+
+/// Reads numbers in the byte order `E` from the front of a byte slice.
+#[derive(Clone, Debug)]
+pub struct Reader<'a, E: Endian> {
+    buf: &'a [u8],
+    endian: PhantomData<E>,
+}
+
+impl<'a, E: Endian> Reader<'a, E> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            endian: PhantomData,
+        }
+    }
+
+    /// Returns the bytes that haven't been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    /// Reads a number, returning `None` without consuming anything if there
+    /// are not enough bytes left.
+    pub fn read<T: Number>(&mut self) -> Option<T> {
+        let len = <T::ByteArray as Array>::N;
+        let bytes = T::ByteArray::try_from_slice(self.buf.get(..len)?)?;
+        self.buf = &self.buf[len..];
+        Some(E::from_bytes(bytes))
+    }
+}
+
+/// The error returned by [`Writer::write`] when the buffer is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferFullError;
+
+impl Display for BufferFullError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("not enough space left in buffer")
+    }
+}
+
+impl Error for BufferFullError {}
+
+/// Writes numbers in the byte order `E` to a byte slice.
+#[derive(Debug)]
+pub struct Writer<'a, E: Endian> {
+    buf: &'a mut [u8],
+    pos: usize,
+    endian: PhantomData<E>,
+}
+
+impl<'a, E: Endian> Writer<'a, E> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            endian: PhantomData,
+        }
+    }
+
+    /// Returns the bytes that have been written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Appends a number, writing nothing if there is not enough space left.
+    pub fn write<T: Number>(&mut self, v: T) -> Result<(), BufferFullError> {
+        let bytes = E::to_bytes(v);
+        let bytes = bytes.as_slice();
+        let end = self.pos + bytes.len();
+        self.buf
+            .get_mut(self.pos..end)
+            .ok_or(BufferFullError)?
+            .copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+}
+
+/// Reinterprets `bytes` as `M` little-endian numbers, returning `None` if `N`
+/// is not `M` times the size of `T`.
+///
+/// `M` can't be computed from `N` on stable Rust, so it is a separate
+/// parameter, usually inferred from the type of the result:
+///
+/// ```
+/// use std_traits::bytes::chunk_bytes_to_numbers;
+///
+/// let words: Option<[u16; 2]> = chunk_bytes_to_numbers(&[1, 0, 2, 0]);
+/// assert_eq!(words, Some([1, 2]));
+/// ```
+pub fn chunk_bytes_to_numbers<T: Number, const N: usize, const M: usize>(
+    bytes: &[u8; N],
+) -> Option<[T; M]> {
+    if N != M * size_of::<T>() {
+        return None;
+    }
+    let mut reader = Reader::<LittleEndian>::new(bytes);
+    let mut numbers = [T::ZERO; M];
+    for v in &mut numbers {
+        *v = reader.read()?;
+    }
+    Some(numbers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reader() {
+        let buf = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+        let mut reader = Reader::<BigEndian>::new(&buf);
+        assert_eq!(reader.read::<u8>(), Some(0x01));
+        assert_eq!(reader.read::<u16>(), Some(0x0203));
+        assert_eq!(reader.read::<u32>(), Some(0x0405_0607));
+        assert_eq!(reader.read::<u16>(), None);
+        assert_eq!(reader.remaining(), [0x08]);
+        assert_eq!(reader.read::<i8>(), Some(0x08));
+        assert_eq!(reader.read::<u8>(), None);
+
+        let mut reader = Reader::<LittleEndian>::new(&buf);
+        assert_eq!(reader.read::<u16>(), Some(0x0201));
+        assert_eq!(reader.read::<f32>(), Some(f32::from_bits(0x0605_0403)));
+
+        let mut reader = Reader::<NativeEndian>::new(&buf);
+        assert_eq!(reader.read::<u64>(), Some(u64::from_ne_bytes(buf)));
+        assert!(reader.remaining().is_empty());
+    }
+
+    #[test]
+    fn test_writer() {
+        let mut buf = [0; 8];
+        let mut writer = Writer::<BigEndian>::new(&mut buf);
+        assert_eq!(writer.write(0x01u8), Ok(()));
+        assert_eq!(writer.write(0x0203u16), Ok(()));
+        assert_eq!(writer.write(-2i32), Ok(()));
+        assert_eq!(writer.write(0u16), Err(BufferFullError));
+        assert_eq!(writer.written(), [0x01, 0x02, 0x03, 0xff, 0xff, 0xff, 0xfe]);
+        assert_eq!(writer.write(0x08u8), Ok(()));
+        assert_eq!(writer.write(0u8), Err(BufferFullError));
+        assert_eq!(buf, [0x01, 0x02, 0x03, 0xff, 0xff, 0xff, 0xfe, 0x08]);
+
+        let mut reader = Reader::<BigEndian>::new(&buf);
+        assert_eq!(reader.read::<u8>(), Some(0x01));
+        assert_eq!(reader.read::<u16>(), Some(0x0203));
+        assert_eq!(reader.read::<i32>(), Some(-2));
+        assert_eq!(reader.read::<u8>(), Some(0x08));
+    }
+
+    #[test]
+    fn test_writer_roundtrip() {
+        fn roundtrip<E: Endian>() {
+            let mut buf = [0; 15];
+            let mut writer = Writer::<E>::new(&mut buf);
+            writer.write(1.5f64).unwrap();
+            writer.write(-7i16).unwrap();
+            writer.write(u32::MAX - 1).unwrap();
+            writer.write(9u8).unwrap();
+
+            let mut reader = Reader::<E>::new(&buf);
+            assert_eq!(reader.read::<f64>(), Some(1.5));
+            assert_eq!(reader.read::<i16>(), Some(-7));
+            assert_eq!(reader.read::<u32>(), Some(u32::MAX - 1));
+            assert_eq!(reader.read::<u8>(), Some(9));
+            assert!(reader.remaining().is_empty());
+        }
+
+        roundtrip::<BigEndian>();
+        roundtrip::<LittleEndian>();
+        roundtrip::<NativeEndian>();
+
+        let mut buf = [0; 4];
+        Writer::<LittleEndian>::new(&mut buf)
+            .write(0x0102_0304u32)
+            .unwrap();
+        assert_eq!(buf, [0x04, 0x03, 0x02, 0x01]);
+    }
+
+    #[test]
+    fn test_chunk_bytes_to_numbers() {
+        let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        assert_eq!(
+            chunk_bytes_to_numbers::<u16, 8, 4>(&bytes),
+            Some([0x0201, 0x0403, 0x0605, 0x0807])
+        );
+        assert_eq!(
+            chunk_bytes_to_numbers::<u32, 8, 2>(&bytes),
+            Some([0x0403_0201, 0x0807_0605])
+        );
+        assert_eq!(
+            chunk_bytes_to_numbers::<i64, 8, 1>(&bytes),
+            Some([0x0807_0605_0403_0201])
+        );
+        assert_eq!(chunk_bytes_to_numbers::<u16, 8, 3>(&bytes), None);
+        assert_eq!(chunk_bytes_to_numbers::<u16, 8, 5>(&bytes), None);
+        assert_eq!(chunk_bytes_to_numbers::<u32, 3, 0>(&[1, 2, 3]), None);
+        assert_eq!(chunk_bytes_to_numbers::<u32, 0, 0>(&[]), Some([]));
+    }
+}
@@ -0,0 +1,171 @@
+//! Const traits for numbers.
+//!
+//! Requires a nightly compiler and the `const_trait` feature.
+//!
+//! Only a subset of the [`Integer`](crate::num::Integer) methods are available
+//! here. Methods that can't be implemented in a const context, such as
+//! [`Number::as_mut_bytes`](crate::num::Number::as_mut_bytes) which transmutes
+//! a reference, are left out. So are the methods of the [`NumberLike`] trait,
+//! as it isn't a const trait.
+
+use crate::num::NumberLike;
+
+/// Const versions of the bit manipulation methods of
+/// [`Integer`](crate::num::Integer).
+pub const trait ConstInteger: NumberLike {
+    /// See [`i32::count_ones`].
+    fn count_ones(self) -> u32;
+
+    /// See [`i32::count_zeros`].
+    fn count_zeros(self) -> u32;
+
+    /// See [`i32::leading_zeros`].
+    fn leading_zeros(self) -> u32;
+
+    /// See [`i32::trailing_zeros`].
+    fn trailing_zeros(self) -> u32;
+
+    /// See [`i32::leading_ones`].
+    fn leading_ones(self) -> u32;
+
+    /// See [`i32::trailing_ones`].
+    fn trailing_ones(self) -> u32;
+
+    /// See [`i32::rotate_left`].
+    fn rotate_left(self, n: u32) -> Self;
+
+    /// See [`i32::rotate_right`].
+    fn rotate_right(self, n: u32) -> Self;
+
+    /// See [`i32::swap_bytes`].
+    fn swap_bytes(self) -> Self;
+
+    /// See [`i32::reverse_bits`].
+    fn reverse_bits(self) -> Self;
+
+    /// See [`i32::from_be`].
+    fn from_be(x: Self) -> Self;
+
+    /// See [`i32::from_le`].
+    fn from_le(x: Self) -> Self;
+
+    /// See [`i32::to_be`].
+    fn to_be(self) -> Self;
+
+    /// See [`i32::to_le`].
+    fn to_le(self) -> Self;
+
+    /// See [`i32::from_be_bytes`].
+    fn from_be_bytes(bytes: Self::ByteArray) -> Self;
+
+    /// See [`i32::from_le_bytes`].
+    fn from_le_bytes(bytes: Self::ByteArray) -> Self;
+
+    /// See [`i32::from_ne_bytes`].
+    fn from_ne_bytes(bytes: Self::ByteArray) -> Self;
+}
+
+macro_rules! impl_const_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl const ConstInteger for $ty {
+                fn count_ones(self) -> u32 {
+                    Self::count_ones(self)
+                }
+
+                fn count_zeros(self) -> u32 {
+                    Self::count_zeros(self)
+                }
+
+                fn leading_zeros(self) -> u32 {
+                    Self::leading_zeros(self)
+                }
+
+                fn trailing_zeros(self) -> u32 {
+                    Self::trailing_zeros(self)
+                }
+
+                fn leading_ones(self) -> u32 {
+                    Self::leading_ones(self)
+                }
+
+                fn trailing_ones(self) -> u32 {
+                    Self::trailing_ones(self)
+                }
+
+                fn rotate_left(self, n: u32) -> Self {
+                    Self::rotate_left(self, n)
+                }
+
+                fn rotate_right(self, n: u32) -> Self {
+                    Self::rotate_right(self, n)
+                }
+
+                fn swap_bytes(self) -> Self {
+                    Self::swap_bytes(self)
+                }
+
+                fn reverse_bits(self) -> Self {
+                    Self::reverse_bits(self)
+                }
+
+                fn from_be(x: Self) -> Self {
+                    Self::from_be(x)
+                }
+
+                fn from_le(x: Self) -> Self {
+                    Self::from_le(x)
+                }
+
+                fn to_be(self) -> Self {
+                    Self::to_be(self)
+                }
+
+                fn to_le(self) -> Self {
+                    Self::to_le(self)
+                }
+
+                fn from_be_bytes(bytes: Self::ByteArray) -> Self {
+                    Self::from_be_bytes(bytes)
+                }
+
+                fn from_le_bytes(bytes: Self::ByteArray) -> Self {
+                    Self::from_le_bytes(bytes)
+                }
+
+                fn from_ne_bytes(bytes: Self::ByteArray) -> Self {
+                    Self::from_ne_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_const_count_ones() {
+        const fn count_ones<T: [const] ConstInteger>(v: T) -> u32 {
+            v.count_ones()
+        }
+
+        const ONES: u32 = count_ones(0b1011u8);
+        assert_eq!(ONES, 3);
+    }
+
+    #[test]
+    fn test_const_from_be_bytes() {
+        const fn from_be_bytes<T: [const] ConstInteger>(bytes: T::ByteArray) -> T {
+            T::from_be_bytes(bytes)
+        }
+
+        const V: u32 = from_be_bytes([0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(V, 0x1234_5678);
+        const W: i16 = from_be_bytes::<i16>([0x80, 0x00]).rotate_left(1);
+        assert_eq!(W, 1);
+    }
+}
@@ -5,6 +5,32 @@ pub trait FunctionPointer: Primitive + Copy + Sized {
     type Return;
 
     fn call(self, args: Self::Args) -> Self::Return;
+
+    /// Returns a function calling `self` and then passing its result to `g`.
+    fn compose<G>(self, g: G) -> impl Fn(Self::Args) -> G::Return
+    where
+        G: FunctionPointer<Args = (Self::Return,)>,
+    {
+        move |args| g.call((self.call(args),))
+    }
+
+    /// Returns the number of arguments, i.e. [`Tuple::N`] of
+    /// [`Args`](FunctionPointer::Args).
+    fn arg_count(&self) -> usize {
+        Self::Args::N
+    }
+}
+
+/// A [`FunctionPointer`] with at least one argument, which can be partially
+/// applied to its first argument.
+pub trait CurryFirst: FunctionPointer {
+    type First;
+    /// The arguments after the first one.
+    type RestArgs: Tuple;
+
+    /// Returns a function calling `self` with `first` followed by the rest of
+    /// the arguments.
+    fn curry_first(self, first: Self::First) -> impl FnOnce(Self::RestArgs) -> Self::Return;
 }
 
 impl<R> Primitive for fn() -> R {}
@@ -38,6 +64,20 @@ impl<A1, R> FunctionPointer for fn(A1) -> R {
     }
 }
 
+#[cfg_attr(docsrs, doc(fake_variadic))]
+#[cfg_attr(
+    docsrs,
+    doc = "This trait is implemented for function pointers with up to 12 arguments."
+)]
+impl<A1, R> CurryFirst for fn(A1) -> R {
+    type First = A1;
+    type RestArgs = ();
+
+    fn curry_first(self, first: Self::First) -> impl FnOnce(Self::RestArgs) -> Self::Return {
+        move |()| self(first)
+    }
+}
+
 macro_rules! impl_fn {
     ($($args:tt $n:tt),*) => {
         #[cfg_attr(docsrs, doc(hidden))]
@@ -70,9 +110,60 @@ impl_fn!(A1 0, A2 1, A3 2, A4 3, A5 4, A6 5, A7 6, A8 7, A9 8, A10 9);
 impl_fn!(A1 0, A2 1, A3 2, A4 3, A5 4, A6 5, A7 6, A8 7, A9 8, A10 9, A11 10);
 impl_fn!(A1 0, A2 1, A3 2, A4 3, A5 4, A6 5, A7 6, A8 7, A9 8, A10 9, A11 10, A12 11);
 
+macro_rules! impl_curry {
+    ($first:tt; $($rest:tt $n:tt),+) => {
+        #[cfg_attr(docsrs, doc(hidden))]
+        impl<$first, $($rest,)+ R> CurryFirst for fn($first, $($rest,)+) -> R {
+            type First = $first;
+            type RestArgs = ($($rest,)+);
+
+            fn curry_first(
+                self,
+                first: Self::First,
+            ) -> impl FnOnce(Self::RestArgs) -> Self::Return {
+                move |rest| self(first, $(rest.$n),+)
+            }
+        }
+    }
+}
+
+/*
+for n in range(2, 13):
+    print(f"impl_curry!(A1; {', '.join(f'A{i + 2} {i}' for i in range(n - 1))});")
+*/
+impl_curry!(A1; A2 0);
+impl_curry!(A1; A2 0, A3 1);
+impl_curry!(A1; A2 0, A3 1, A4 2);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5, A8 6);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5, A8 6, A9 7);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5, A8 6, A9 7, A10 8);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5, A8 6, A9 7, A10 8, A11 9);
+impl_curry!(A1; A2 0, A3 1, A4 2, A5 3, A6 4, A7 5, A8 6, A9 7, A10 8, A11 9, A12 10);
+
+/// Calls `f` with the items of `args` as arguments.
+///
+/// This is the same as [`FunctionPointer::call`] and
+/// [`Tuple::apply_to`].
+pub fn apply<F: FunctionPointer>(f: F, args: F::Args) -> F::Return {
+    f.call(args)
+}
+
+/// Returns a function pointer returning its argument unchanged.
+pub fn identity<T>() -> fn(T) -> T {
+    |v| v
+}
+
+/// Returns a function ignoring its argument and always returning `value`.
+pub fn constant<T: Copy, U>(value: T) -> impl Fn(U) -> T {
+    move |_| value
+}
+
 #[cfg(test)]
 mod test {
-    use super::FunctionPointer;
+    use super::{apply, constant, identity, CurryFirst, FunctionPointer};
 
     fn f0() {}
     fn f1<T>(a: T) -> T {
@@ -110,4 +201,83 @@ mod test {
             (1usize, "b", false)
         );
     }
+
+    #[test]
+    fn test_compose() {
+        fn double(a: i32) -> i32 {
+            a * 2
+        }
+        fn add_one(a: i32) -> i32 {
+            a + 1
+        }
+
+        let f = (double as fn(_) -> _).compose(add_one as fn(_) -> _);
+        assert_eq!(f((3,)), 7);
+
+        let f = (f2 as fn(u8, u8) -> _).compose(f1 as fn((u8, u8)) -> _);
+        assert_eq!(f((1, 2)), (1u8, 2u8));
+
+        let f = (f0 as fn() -> _).compose(f1 as fn(()) -> _);
+        f(());
+    }
+
+    #[test]
+    fn test_curry_first() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let add_two = (add as fn(_, _) -> _).curry_first(2);
+        assert_eq!(add_two((3,)), 5);
+
+        let f = (f3 as fn(u8, char, bool) -> _).curry_first(1);
+        assert_eq!(f(('a', true)), (1u8, 'a', true));
+
+        let f = (f1 as fn(&'static str) -> _).curry_first("x");
+        assert_eq!(f(()), "x");
+    }
+
+    #[test]
+    fn test_identity() {
+        fn call<F: FunctionPointer<Args = (i32,), Return = i32>>(f: F, v: i32) -> i32 {
+            f.call((v,))
+        }
+
+        assert_eq!(call(identity(), 42), 42);
+        assert_eq!(identity::<&str>()("a"), "a");
+    }
+
+    #[test]
+    fn test_constant() {
+        let f = constant(7u8);
+        assert_eq!(f("ignored"), 7);
+        assert_eq!(f("also ignored"), 7);
+
+        let f = identity::<i32>().compose(identity());
+        assert_eq!([1, 2].map(|v| f((v,))), [1, 2]);
+        assert_eq!([1, 2].map(constant(0)), [0, 0]);
+    }
+
+    #[test]
+    fn test_apply() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        assert_eq!(apply(add as fn(_, _) -> _, (2, 3)), 5);
+        assert_eq!(apply(f0 as fn(), ()), ());
+        assert_eq!(apply(f1 as fn(u8) -> u8, (7,)), 7);
+    }
+
+    #[test]
+    fn test_arg_count() {
+        fn add3(a: u8, b: u8, c: u8) -> u8 {
+            a + b + c
+        }
+
+        assert_eq!((add3 as fn(u8, u8, u8) -> u8).arg_count(), 3);
+        assert_eq!((f0 as fn()).arg_count(), 0);
+        assert_eq!((f1 as fn(i32) -> i32).arg_count(), 1);
+        assert_eq!((f2 as fn(u8, char) -> _).arg_count(), 2);
+    }
 }
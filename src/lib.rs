@@ -1,5 +1,11 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(rustdoc_internals))]
+#![cfg_attr(feature = "const_trait", feature(const_trait_impl))]
+#![cfg_attr(feature = "ptr_metadata", feature(ptr_metadata))]
+#![cfg_attr(
+    feature = "const_raw_ptr_comparison",
+    feature(const_raw_ptr_comparison)
+)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -8,6 +14,10 @@ extern crate alloc;
 extern crate std;
 
 pub mod array;
+pub mod bits;
+pub mod bytes;
+#[cfg(feature = "const_trait")]
+pub mod const_num;
 pub mod fun;
 pub mod num;
 pub mod primitive;
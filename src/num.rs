@@ -16,11 +16,12 @@
 
 use core::{
     cmp::Ordering,
-    fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
-    hash::Hash,
+    error::Error,
+    fmt::{self, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
+    hash::{Hash, Hasher},
     iter::{Product, Sum},
     mem::{size_of, transmute},
-    num::{FpCategory, ParseIntError},
+    num::{FpCategory, IntErrorKind, ParseIntError},
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
         DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
@@ -30,6 +31,7 @@ use core::{
     str::FromStr,
 };
 
+use self::wide::{WideInteger, WideNumber, Widen, WidenInteger};
 use crate::{array::Array, primitive::Primitive};
 
 pub trait NumberLike:
@@ -58,10 +60,27 @@ pub trait NumberLike:
     /// value.
     const MAX: Self;
 
+    /// The number of distinct valid values of `Self`.
+    ///
+    /// For [`bool`] and [`char`] only valid values are counted, so surrogates
+    /// are excluded for [`char`]. For floats this is the number of bit
+    /// patterns, so every NaN payload is counted. As `2^128` does not fit in a
+    /// [`u128`], this saturates to [`u128::MAX`] for 128-bit integers.
+    const CARDINALITY: u128;
+
     type Underlying: Number;
     type ByteArray: Array<Item = u8>;
+    /// A byte array twice the size of [`ByteArray`](NumberLike::ByteArray),
+    /// holding the hex encoding of `Self`.
+    #[cfg(feature = "hex")]
+    type HexArray: Array<Item = u8>;
 
     fn to_underlying(self) -> Self::Underlying;
+    /// Returns `None` if `underlying` is not a valid value of `Self`, e.g. a
+    /// surrogate or out-of-range code point for [`char`], or anything other
+    /// than `0` or `1` for [`bool`].
+    ///
+    /// The `try_from_*bytes` functions all validate through this function.
     fn try_from_underlying(underlying: Self::Underlying) -> Option<Self>;
     fn to_bytes(self) -> Self::ByteArray;
     fn try_from_bytes(bytes: Self::ByteArray) -> Option<Self>;
@@ -72,6 +91,49 @@ pub trait NumberLike:
     fn try_from_be_bytes(bytes: Self::ByteArray) -> Option<Self>;
     fn try_from_le_bytes(bytes: Self::ByteArray) -> Option<Self>;
     fn try_from_ne_bytes(bytes: Self::ByteArray) -> Option<Self>;
+
+    /// Same as [`try_from_be_bytes`](NumberLike::try_from_be_bytes), but also
+    /// returns `None` if the length of `bytes` doesn't match.
+    fn try_from_be_slice(bytes: &[u8]) -> Option<Self>;
+    /// Same as [`try_from_le_bytes`](NumberLike::try_from_le_bytes), but also
+    /// returns `None` if the length of `bytes` doesn't match.
+    fn try_from_le_slice(bytes: &[u8]) -> Option<Self>;
+    /// Same as [`try_from_ne_bytes`](NumberLike::try_from_ne_bytes), but also
+    /// returns `None` if the length of `bytes` doesn't match.
+    fn try_from_ne_slice(bytes: &[u8]) -> Option<Self>;
+
+    /// Writes the big-endian bytes of `self` into `out`.
+    fn write_be_bytes(self, out: &mut Self::ByteArray);
+    /// Writes the little-endian bytes of `self` into `out`.
+    fn write_le_bytes(self, out: &mut Self::ByteArray);
+    /// Writes the native-endian bytes of `self` into `out`.
+    fn write_ne_bytes(self, out: &mut Self::ByteArray);
+
+    /// Returns the big-endian bytes of `self` encoded as lowercase hex digits.
+    #[cfg(feature = "hex")]
+    fn to_hex(self) -> Self::HexArray;
+    /// Returns the little-endian bytes of `self` encoded as lowercase hex
+    /// digits.
+    #[cfg(feature = "hex")]
+    fn to_hex_le(self) -> Self::HexArray;
+    /// Decodes big-endian bytes encoded as hex digits of any case, returning
+    /// `None` if `hex` contains other characters or if the bytes are not a
+    /// valid value of `Self`.
+    #[cfg(feature = "hex")]
+    fn from_hex(hex: Self::HexArray) -> Option<Self>;
+    /// Same as [`from_hex`](NumberLike::from_hex), but for little-endian
+    /// bytes.
+    #[cfg(feature = "hex")]
+    fn from_hex_le(hex: Self::HexArray) -> Option<Self>;
+
+    /// Maps `self` to a [`u128`] key, such that the order of the keys matches
+    /// the order of the values.
+    ///
+    /// Signed integers are offset so that [`MIN`](NumberLike::MIN) maps to
+    /// `0`, and floats are ordered like [`total_cmp`](Float::total_cmp), so
+    /// `-0.0` has a smaller key than `0.0` and NaNs are ordered by their sign
+    /// and payload. [`bool`] and [`char`] use their underlying value.
+    fn to_u128_key(self) -> u128;
 }
 
 macro_rules! impl_number_like {
@@ -80,18 +142,23 @@ macro_rules! impl_number_like {
         underlying: $number:ty,
         min: $min:expr,
         max: $max:expr,
-        try_from_underlying: $try_from_underlying:expr
+        cardinality: $cardinality:expr,
+        try_from_underlying: $try_from_underlying:expr,
+        to_u128_key: $to_u128_key:expr
     ) => {
         impl Primitive for $ty {}
         impl NumberLike for $ty {
             const MIN: Self = $min;
             const MAX: Self = $max;
+            const CARDINALITY: u128 = $cardinality;
 
             type Underlying = $number;
             type ByteArray = [u8; size_of::<Self>()];
+            #[cfg(feature = "hex")]
+            type HexArray = [u8; 2 * size_of::<Self>()];
 
             fn to_underlying(self) -> Self::Underlying {
-                #[allow(clippy::useless_transmute)]
+                #[allow(unknown_lints, clippy::useless_transmute, unnecessary_transmutes)]
                 unsafe {
                     transmute::<Self, Self::Underlying>(self)
                 }
@@ -102,7 +169,7 @@ macro_rules! impl_number_like {
             }
 
             fn to_bytes(self) -> Self::ByteArray {
-                #[allow(clippy::transmute_num_to_bytes)]
+                #[allow(unknown_lints, unnecessary_transmutes)]
                 unsafe {
                     transmute::<Self, Self::ByteArray>(self)
                 }
@@ -135,27 +202,128 @@ macro_rules! impl_number_like {
             fn try_from_ne_bytes(bytes: Self::ByteArray) -> Option<Self> {
                 Self::try_from_underlying(Self::Underlying::from_ne_bytes(bytes))
             }
+
+            fn try_from_be_slice(bytes: &[u8]) -> Option<Self> {
+                Self::try_from_be_bytes(bytes.try_into().ok()?)
+            }
+
+            fn try_from_le_slice(bytes: &[u8]) -> Option<Self> {
+                Self::try_from_le_bytes(bytes.try_into().ok()?)
+            }
+
+            fn try_from_ne_slice(bytes: &[u8]) -> Option<Self> {
+                Self::try_from_ne_bytes(bytes.try_into().ok()?)
+            }
+
+            fn write_be_bytes(self, out: &mut Self::ByteArray) {
+                *out = self.to_be_bytes();
+            }
+
+            fn write_le_bytes(self, out: &mut Self::ByteArray) {
+                *out = self.to_le_bytes();
+            }
+
+            fn write_ne_bytes(self, out: &mut Self::ByteArray) {
+                *out = self.to_ne_bytes();
+            }
+
+            #[cfg(feature = "hex")]
+            fn to_hex(self) -> Self::HexArray {
+                hex_encode(self.to_be_bytes())
+            }
+
+            #[cfg(feature = "hex")]
+            fn to_hex_le(self) -> Self::HexArray {
+                hex_encode(self.to_le_bytes())
+            }
+
+            #[cfg(feature = "hex")]
+            fn from_hex(hex: Self::HexArray) -> Option<Self> {
+                Self::try_from_be_bytes(hex_decode(hex)?)
+            }
+
+            #[cfg(feature = "hex")]
+            fn from_hex_le(hex: Self::HexArray) -> Option<Self> {
+                Self::try_from_le_bytes(hex_decode(hex)?)
+            }
+
+            fn to_u128_key(self) -> u128 {
+                $to_u128_key(self)
+            }
         }
+
+        // The byte array transmutes above rely on these sizes matching.
+        const _: () = {
+            type ByteArray = <$ty as NumberLike>::ByteArray;
+            type UnderlyingByteArray = <<$ty as NumberLike>::Underlying as NumberLike>::ByteArray;
+            assert!(size_of::<ByteArray>() == size_of::<$ty>());
+            assert!(size_of::<ByteArray>() == size_of::<UnderlyingByteArray>());
+        };
     };
 }
 
+#[cfg(feature = "hex")]
+fn hex_encode<const N: usize, const M: usize>(bytes: [u8; N]) -> [u8; M] {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut hex = [0; M];
+    for (pair, byte) in hex.chunks_exact_mut(2).zip(bytes) {
+        pair[0] = DIGITS[usize::from(byte >> 4)];
+        pair[1] = DIGITS[usize::from(byte & 0xf)];
+    }
+    hex
+}
+
+#[cfg(feature = "hex")]
+fn hex_decode<const N: usize, const M: usize>(hex: [u8; M]) -> Option<[u8; N]> {
+    let digit = |c: u8| char::from(c).to_digit(16);
+
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (digit(pair[0])? << 4 | digit(pair[1])?) as u8;
+    }
+    Some(bytes)
+}
+
 impl_number_like!(bool,
     underlying: u8,
     min: false,
     max: true,
+    cardinality: 2,
     try_from_underlying: |v| match v {
         0 => Some(false),
         1 => Some(true),
         _ => None,
-    }
+    },
+    to_u128_key: |v| v as u128
 );
 impl_number_like!(char,
     underlying: u32,
     min: '\0',
     max: '\u{10ffff}',
-    try_from_underlying: |v| char::try_from(v).ok()
+    // All code points except the surrogates `0xd800..=0xdfff`.
+    cardinality: 0x110000 - 0x800,
+    try_from_underlying: |v| char::try_from(v).ok(),
+    to_u128_key: |v| v as u128
 );
 
+/// A [`NumberLike`] that is its own [`Underlying`](NumberLike::Underlying)
+/// type, so converting to and from the underlying type is a no-op.
+///
+/// This is implemented for all [`Number`]s, but not for restricted types like
+/// [`bool`] and [`char`]:
+///
+/// ```compile_fail
+/// use std_traits::num::TransparentNumber;
+///
+/// fn transparent<T: TransparentNumber>() {}
+///
+/// transparent::<char>();
+/// ```
+pub trait TransparentNumber: NumberLike<Underlying = Self> {}
+
+impl<T: NumberLike<Underlying = T>> TransparentNumber for T {}
+
 pub trait Number:
     NumberLike
     + LowerExp
@@ -187,11 +355,27 @@ pub trait Number:
     + TryFrom<i16>
     + Sum
     + Product
+    + Widen
 {
     const ZERO: Self;
     const ONE: Self;
     const TWO: Self;
 
+    /// Whether `Self` is a floating point type.
+    const IS_FLOAT: bool;
+
+    /// Whether `Self` is an integer type.
+    const IS_INTEGER: bool;
+
+    /// The unsigned integer type with the same size as `Self`.
+    type Bits: Unsigned;
+
+    /// Returns the value with all bits set to zero.
+    ///
+    /// This is a safe alternative to [`mem::zeroed`](core::mem::zeroed), and
+    /// is equal to [`ZERO`](Number::ZERO) for all number types.
+    fn zeroed() -> Self;
+
     fn from_bytes(bytes: Self::ByteArray) -> Self;
     fn as_mut_bytes(&mut self) -> &mut Self::ByteArray;
 
@@ -202,9 +386,134 @@ pub trait Number:
     /// See [`i32::abs`].
     fn abs(self) -> Self;
 
-    /// See [`i32::signum`].
+    /// See [`i32::signum`] and [`f32::signum`].
+    ///
+    /// For unsigned integers this is `0` or `1`.
     fn signum(self) -> Self;
 
+    /// See [`i32::saturating_abs`].
+    ///
+    /// For unsigned integers this is the identity, and for floats it is
+    /// [`abs`](Number::abs).
+    fn saturating_abs(self) -> Self;
+
+    /// See [`i32::wrapping_abs`].
+    ///
+    /// For unsigned integers this is the identity, and for floats it is
+    /// [`abs`](Number::abs).
+    fn wrapping_abs(self) -> Self;
+
+    /// Returns `1 / self`, or `None` if the division is not defined.
+    ///
+    /// For integers this uses integer division, so the result is `0` unless
+    /// `self` is `1` or `-1`, and `None` for zero. For floats this is always
+    /// `Some`, with the reciprocal of zero being an infinity.
+    fn checked_recip(self) -> Option<Self>;
+
+    /// Returns the bit pattern of `self`.
+    ///
+    /// See [`f32::to_bits`]. For integers this is the same as
+    /// [`to_unsigned`](Integer::to_unsigned).
+    fn to_bits(self) -> Self::Bits;
+
+    /// Creates a value from its bit pattern.
+    ///
+    /// See [`f32::from_bits`]. For integers this is the inverse of
+    /// [`to_bits`](Number::to_bits).
+    fn from_bits(v: Self::Bits) -> Self;
+
+    /// Returns `-1`, `0` or `1` depending on the sign of `self`.
+    ///
+    /// Unlike [`signum`](Number::signum), this returns `0` for both `0.0` and
+    /// `-0.0`, as well as for NaN.
+    fn signum_i8(self) -> i8;
+
+    /// Returns `self != 0`.
+    ///
+    /// This is the inverse of the [`From<bool>`] conversion. Note that both
+    /// `0.0` and `-0.0` are `false`, while NaN is `true` as it is not equal
+    /// to zero.
+    fn to_bool(self) -> bool;
+
+    /// See [`i32::midpoint`] and [`f32::midpoint`].
+    fn midpoint(self, other: Self) -> Self;
+
+    /// Returns the minimum of `self` and `other`.
+    ///
+    /// See [`Ord::min`] and [`f32::min`]. For floats, if one of the arguments
+    /// is NaN the other argument is returned.
+    fn num_min(self, other: Self) -> Self;
+
+    /// Returns the maximum of `self` and `other`.
+    ///
+    /// See [`Ord::max`] and [`f32::max`]. For floats, if one of the arguments
+    /// is NaN the other argument is returned.
+    fn num_max(self, other: Self) -> Self;
+
+    /// Restricts `self` to the interval `[min, max]`.
+    ///
+    /// See [`Ord::clamp`] and [`f32::clamp`]. Panics if `min > max`, or for
+    /// floats if either bound is NaN.
+    fn num_clamp(self, min: Self, max: Self) -> Self;
+
+    /// Feeds `self` into `state`, like [`Hash::hash`].
+    ///
+    /// Integers are hashed normally. Floats are hashed by their bit pattern,
+    /// except that all NaNs hash identically, and `0.0` and `-0.0` hash
+    /// identically. This is consistent with [`PartialEq`] for `0.0` and
+    /// `-0.0`, but unlike [`PartialEq`] NaN is treated as equal to itself.
+    fn hash_bits<H: Hasher>(&self, state: &mut H);
+
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// Integers are compared with [`Ord::cmp`]. Floats are compared with
+    /// [`total_cmp`](Float::total_cmp), so positive NaNs sort after all other
+    /// values, negative NaNs sort before all other values, and `-0.0` sorts
+    /// before `0.0`.
+    fn num_cmp(self, other: Self) -> Ordering;
+
+    /// Returns whichever of `self` and `other` has the smaller absolute
+    /// value, keeping its sign.
+    ///
+    /// If the absolute values are equal, `self` is returned. For floats, if
+    /// one of the arguments is NaN the other argument is returned. Unlike
+    /// [`abs`](Number::abs), this never overflows for signed integers.
+    fn min_by_magnitude(self, other: Self) -> Self;
+
+    /// Returns whichever of `self` and `other` has the larger absolute value,
+    /// keeping its sign.
+    ///
+    /// If the absolute values are equal, `self` is returned. For floats, if
+    /// one of the arguments is NaN the other argument is returned. Unlike
+    /// [`abs`](Number::abs), this never overflows for signed integers.
+    fn max_by_magnitude(self, other: Self) -> Self;
+
+    /// Converts `self` to `U`, saturating at the bounds of `U` if the value is
+    /// out of range.
+    ///
+    /// NaN is converted to zero for integers. Infinities are preserved for
+    /// floats, while finite values are saturated to the largest finite values.
+    /// Conversions to floats go through [`f64`], so large 64-bit and 128-bit
+    /// integers may lose precision.
+    fn saturating_cast<U: Number>(self) -> U;
+
+    /// Converts `self` to `U` using the given [`CastPolicy`].
+    ///
+    /// This only returns `None` for [`CastPolicy::Checked`].
+    fn cast_with<U: Number>(self, policy: CastPolicy) -> Option<U>;
+
+    /// Returns the smallest finite value of `Self`.
+    ///
+    /// This is [`MIN`](NumberLike::MIN) for integers and
+    /// [`MIN_FINITE`](Float::MIN_FINITE) for floats.
+    fn min_finite() -> Self;
+
+    /// Returns the largest finite value of `Self`.
+    ///
+    /// This is [`MAX`](NumberLike::MAX) for integers and
+    /// [`MAX_FINITE`](Float::MAX_FINITE) for floats.
+    fn max_finite() -> Self;
+
     #[cfg(feature = "std")]
     fn div_euclid(self, rhs: Self) -> Self;
 
@@ -212,6 +521,40 @@ pub trait Number:
     fn rem_euclid(self, rhs: Self) -> Self;
 }
 
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T: super::Number> Sealed for T {}
+}
+
+/// A [`Number`], i.e. an integer or a float, for which every byte pattern is
+/// a valid value.
+///
+/// Unlike [`NumberLike`], this excludes [`bool`] and [`char`]:
+///
+/// ```compile_fail
+/// use std_traits::num::RealNumber;
+///
+/// fn real<T: RealNumber>() {}
+///
+/// real::<bool>();
+/// ```
+///
+/// This trait is sealed and implemented for all [`Number`]s.
+pub trait RealNumber: Number + sealed::Sealed {
+    /// Creates a value from its native-endian bytes, which never fails.
+    ///
+    /// Unlike [`try_from_bytes`](NumberLike::try_from_bytes), this doesn't
+    /// return an [`Option`].
+    fn from_bytes_total(bytes: Self::ByteArray) -> Self;
+}
+
+impl<T: Number> RealNumber for T {
+    fn from_bytes_total(bytes: Self::ByteArray) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
 macro_rules! impl_number {
     (
         $ty:ty,
@@ -219,21 +562,47 @@ macro_rules! impl_number {
         one: $one:expr,
         min: $min:expr,
         max: $max:expr,
+        bits: $bits:ty,
         abs: $abs:expr,
-        signum: $signum:expr
+        signum: $signum:expr,
+        saturating_abs: $saturating_abs:expr,
+        wrapping_abs: $wrapping_abs:expr,
+        checked_recip: $checked_recip:expr,
+        to_bits: $to_bits:expr,
+        from_bits: $from_bits:expr,
+        to_wide: $to_wide:expr,
+        try_from_wide: $try_from_wide:expr,
+        saturating_from_wide: $saturating_from_wide:expr,
+        to_u128_key: $to_u128_key:expr,
+        hash_key: $hash_key:expr,
+        cmp: $cmp:expr,
+        cmp_magnitude: $cmp_magnitude:expr,
+        is_float: $is_float:expr
     ) => {
         impl_number_like!($ty,
             underlying: Self,
             min: $min,
             max: $max,
-            try_from_underlying: |v| Some(v)
+            cardinality: bit_pattern_count(size_of::<Self>()),
+            try_from_underlying: |v| Some(v),
+            to_u128_key: $to_u128_key
         );
         impl Number for $ty {
             const ZERO: Self = $zero;
             const ONE: Self = $one;
             const TWO: Self = $one + $one;
 
+            const IS_FLOAT: bool = $is_float;
+            const IS_INTEGER: bool = !$is_float;
+
+            type Bits = $bits;
+
+            fn zeroed() -> Self {
+                Self::from_ne_bytes([0; size_of::<Self>()])
+            }
+
             fn from_bytes(bytes: Self::ByteArray) -> Self {
+                #[allow(unknown_lints, unnecessary_transmutes)]
                 unsafe { transmute::<Self::ByteArray, Self>(bytes) }
             }
 
@@ -261,6 +630,105 @@ macro_rules! impl_number {
                 $signum(self)
             }
 
+            fn saturating_abs(self) -> Self {
+                $saturating_abs(self)
+            }
+
+            fn wrapping_abs(self) -> Self {
+                $wrapping_abs(self)
+            }
+
+            fn checked_recip(self) -> Option<Self> {
+                $checked_recip(self)
+            }
+
+            fn to_bits(self) -> Self::Bits {
+                $to_bits(self)
+            }
+
+            fn from_bits(v: Self::Bits) -> Self {
+                $from_bits(v)
+            }
+
+            fn signum_i8(self) -> i8 {
+                if self > Self::ZERO {
+                    1
+                } else if self < Self::ZERO {
+                    -1
+                } else {
+                    0
+                }
+            }
+
+            fn to_bool(self) -> bool {
+                self != Self::ZERO
+            }
+
+            fn midpoint(self, other: Self) -> Self {
+                Self::midpoint(self, other)
+            }
+
+            fn num_min(self, other: Self) -> Self {
+                Self::min(self, other)
+            }
+
+            fn num_max(self, other: Self) -> Self {
+                Self::max(self, other)
+            }
+
+            fn num_clamp(self, min: Self, max: Self) -> Self {
+                Self::clamp(self, min, max)
+            }
+
+            fn hash_bits<H: Hasher>(&self, state: &mut H) {
+                $hash_key(*self).hash(state)
+            }
+
+            fn num_cmp(self, other: Self) -> Ordering {
+                $cmp(&self, &other)
+            }
+
+            fn min_by_magnitude(self, other: Self) -> Self {
+                match $cmp_magnitude(self, other) {
+                    Some(Ordering::Greater) => other,
+                    Some(_) => self,
+                    // NaN is the only value not equal to itself.
+                    None if self != self => other,
+                    None => self,
+                }
+            }
+
+            fn max_by_magnitude(self, other: Self) -> Self {
+                match $cmp_magnitude(self, other) {
+                    Some(Ordering::Less) => other,
+                    Some(_) => self,
+                    None if self != self => other,
+                    None => self,
+                }
+            }
+
+            fn saturating_cast<U: Number>(self) -> U {
+                U::saturating_from_wide(self.to_wide())
+            }
+
+            fn cast_with<U: Number>(self, policy: CastPolicy) -> Option<U> {
+                let wide = self.to_wide();
+                match policy {
+                    CastPolicy::Wrap => Some(U::wrapping_from_wide(wide)),
+                    CastPolicy::Saturate => Some(U::saturating_from_wide(wide)),
+                    CastPolicy::Checked => U::try_from_wide(wide),
+                }
+            }
+
+            fn min_finite() -> Self {
+                // The inherent constant, which is finite for floats.
+                Self::MIN
+            }
+
+            fn max_finite() -> Self {
+                Self::MAX
+            }
+
             #[cfg(feature = "std")]
             fn div_euclid(self, rhs: Self) -> Self {
                 Self::div_euclid(self, rhs)
@@ -271,7 +739,328 @@ macro_rules! impl_number {
                 Self::rem_euclid(self, rhs)
             }
         }
+
+        impl Widen for $ty {
+            fn to_wide(self) -> WideNumber {
+                $to_wide(self)
+            }
+
+            fn try_from_wide(wide: WideNumber) -> Option<Self> {
+                $try_from_wide(wide)
+            }
+
+            fn saturating_from_wide(wide: WideNumber) -> Self {
+                $saturating_from_wide(wide)
+            }
+
+            fn wrapping_from_wide(wide: WideNumber) -> Self {
+                match wide {
+                    WideNumber::Signed(v) => v as Self,
+                    WideNumber::Unsigned(v) => v as Self,
+                    WideNumber::Float(v) => v as Self,
+                }
+            }
+        }
+    };
+}
+
+/// Returns the number of bit patterns of a type with `size` bytes, saturating
+/// at [`u128::MAX`].
+const fn bit_pattern_count(size: usize) -> u128 {
+    match 1u128.checked_shl(size as u32 * 8) {
+        Some(count) => count,
+        None => u128::MAX,
+    }
+}
+
+/// Widening of numbers, which the conversions between [`Number`] types are
+/// implemented with.
+///
+/// The items are public so [`Number`] and [`Integer`] can use the traits as
+/// supertraits, but they can't be named outside of this crate.
+mod wide {
+    /// A number widened to the largest primitive type of the same kind.
+    ///
+    /// Signed integers are widened to [`i128`], unsigned integers to [`u128`]
+    /// and floats to [`f64`], so widening is always lossless.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    pub enum WideNumber {
+        Signed(i128),
+        Unsigned(u128),
+        Float(f64),
+    }
+
+    /// An integer widened to [`i128`] or [`u128`] depending on its sign.
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    pub enum WideInteger {
+        Signed(i128),
+        Unsigned(u128),
+    }
+
+    impl From<WideInteger> for WideNumber {
+        fn from(wide: WideInteger) -> Self {
+            match wide {
+                WideInteger::Signed(v) => Self::Signed(v),
+                WideInteger::Unsigned(v) => Self::Unsigned(v),
+            }
+        }
+    }
+
+    pub trait Widen: Sized {
+        /// Widens `self` to a [`WideNumber`] of the same kind.
+        fn to_wide(self) -> WideNumber;
+
+        /// Converts a [`WideNumber`] of any kind to `Self`, returning `None`
+        /// if the value is out of range.
+        ///
+        /// Floats are truncated towards zero when converted to integers, and
+        /// NaN and infinities are never in range for integers. Conversions to
+        /// floats go through [`f64`], so large 64-bit and 128-bit integers may
+        /// lose precision.
+        fn try_from_wide(wide: WideNumber) -> Option<Self>;
+
+        /// Converts a [`WideNumber`] of any kind to `Self`, saturating at the
+        /// bounds of `Self` if the value is out of range.
+        ///
+        /// NaN is converted to zero for integers. Infinities are preserved for
+        /// floats, while finite values are saturated to the largest finite
+        /// values.
+        fn saturating_from_wide(wide: WideNumber) -> Self;
+
+        /// Converts a [`WideNumber`] of any kind to `Self` like an `as` cast
+        /// would.
+        ///
+        /// Integers are truncated or sign-extended, so out of range values
+        /// wrap around. Floats are converted to integers with saturation, and
+        /// NaN is converted to zero.
+        fn wrapping_from_wide(wide: WideNumber) -> Self;
+    }
+
+    pub trait WidenInteger {
+        /// Widens `self` to a [`WideInteger`] of the same sign.
+        fn to_wide_integer(self) -> WideInteger;
+    }
+}
+
+/// How to handle out of range values when converting between [`Number`]s.
+///
+/// See [`Number::cast_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CastPolicy {
+    /// Wrap around like an `as` cast.
+    ///
+    /// Integers are truncated or sign-extended, so out of range values wrap
+    /// around. Floats are converted to integers with saturation, and NaN is
+    /// converted to zero.
+    Wrap,
+    /// Saturate at the bounds of the target type.
+    ///
+    /// See [`Number::saturating_cast`].
+    Saturate,
+    /// Fail with `None`.
+    ///
+    /// See [`num_cast`].
+    Checked,
+}
+
+fn integer_try_from_wide<T: Integer>(wide: WideNumber) -> Option<T> {
+    match wide {
+        WideNumber::Signed(v) => v.try_into().ok(),
+        WideNumber::Unsigned(v) => v.try_into().ok(),
+        WideNumber::Float(v) => {
+            // -2^127 and 2^128, both exactly representable as `f64`.
+            const I128_MIN: f64 = i128::MIN as f64;
+            const U128_END: f64 = u128::MAX as f64;
+            if (I128_MIN..0.0).contains(&v) {
+                (v as i128).try_into().ok()
+            } else if (0.0..U128_END).contains(&v) {
+                (v as u128).try_into().ok()
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn integer_saturating_from_wide<T: Integer>(wide: WideNumber) -> T {
+    integer_try_from_wide(wide).unwrap_or(match wide {
+        WideNumber::Signed(v) if v < 0 => T::MIN,
+        WideNumber::Float(v) if v.is_nan() => T::ZERO,
+        WideNumber::Float(v) if v < 0.0 => T::MIN,
+        _ => T::MAX,
+    })
+}
+
+/// Parses an unsigned hex float, returning the bits of the value in a float
+/// format with the given parameters. See [`Float::from_hex_float`].
+fn parse_hex_float_bits(s: &str, mantissa_digits: u32, min_exp: i32, max_exp: i32) -> Option<u128> {
+    let p = mantissa_digits as i64;
+    let bias = max_exp as i64 - 1;
+    let infinity = ((2 * bias + 1) as u128) << (p - 1);
+    if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
+        return Some(infinity);
+    }
+    if s.eq_ignore_ascii_case("nan") {
+        return Some(infinity | 1 << (p - 2));
+    }
+
+    let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    let (digits, exp) = s.split_once(['p', 'P'])?;
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let (exp_negative, exp_digits) = match exp.strip_prefix('-') {
+        Some(exp) => (true, exp),
+        None => (false, exp.strip_prefix('+').unwrap_or(exp)),
     };
+    if exp_digits.is_empty() {
+        return None;
+    }
+    let mut exp = 0i64;
+    for c in exp_digits.chars() {
+        // Large exponents overflow or underflow anyway, so clamp to avoid
+        // overflowing `exp` itself.
+        exp = (exp * 10 + c.to_digit(10)? as i64).min(1 << 24);
+    }
+    if exp_negative {
+        exp = -exp;
+    }
+
+    // The value is `mant * 2^exp`, where `sticky` is set if any nonzero
+    // digits didn't fit in `mant`.
+    let mut mant = 0u64;
+    let mut sticky = false;
+    for (i, c) in int.chars().chain(frac.chars()).enumerate() {
+        let d = c.to_digit(16)? as u64;
+        let is_frac = i >= int.len();
+        if mant >> 60 == 0 {
+            mant = mant << 4 | d;
+            if is_frac {
+                exp -= 4;
+            }
+        } else {
+            sticky |= d != 0;
+            if !is_frac {
+                exp += 4;
+            }
+        }
+    }
+    if mant == 0 {
+        return Some(0);
+    }
+
+    // Normalize to `m * 2^(e - 63)` with the top bit of `m` set, so the value
+    // is in `[2^e, 2^(e + 1))`.
+    let lz = mant.leading_zeros() as i64;
+    let m = (mant as u128) << lz;
+    let e = exp + 63 - lz;
+    if e > bias {
+        return Some(infinity);
+    }
+
+    // Subnormals have the minimum exponent and fewer digits of precision.
+    let min_normal_exp = min_exp as i64 - 1;
+    let e = e.max(min_normal_exp);
+    let dropped = (64 - p + (e - (exp + 63 - lz))).min(65) as u32;
+    let half = 1u128 << (dropped - 1);
+    let rem = m & ((1 << dropped) - 1);
+    let mut q = m >> dropped;
+    if rem > half || (rem == half && (sticky || q & 1 == 1)) {
+        q += 1;
+    }
+    // `q` includes the implicit leading bit for normal values, which
+    // increments the biased exponent by one. If rounding carried into the
+    // next power of two, it is incremented by two, which may give infinity.
+    Some((((e + bias - 1) as u128) << (p - 1)) + q)
+}
+
+/// The formatting of [`Float::to_hex_float`].
+enum HexFloat {
+    Nan,
+    Infinite {
+        negative: bool,
+    },
+    Finite {
+        negative: bool,
+        /// The digit before the point, `1` for normal values and `0` otherwise.
+        lead: u8,
+        /// The digits after the point.
+        frac: u64,
+        frac_digits: u32,
+        exp: i32,
+    },
+}
+
+impl HexFloat {
+    fn new(bits: u64, total_bits: u32, mantissa_digits: u32, max_exp: i32) -> Self {
+        let frac_bits = mantissa_digits - 1;
+        let exp_bits = total_bits - 1 - frac_bits;
+        let negative = bits >> (total_bits - 1) & 1 == 1;
+        let biased_exp = (bits >> frac_bits & ((1 << exp_bits) - 1)) as i32;
+        let frac = bits & ((1 << frac_bits) - 1);
+        let bias = max_exp - 1;
+
+        let (lead, exp) = match biased_exp {
+            0 if frac == 0 => (0, 0),
+            0 => (0, 1 - bias),
+            _ if biased_exp == 2 * bias + 1 => {
+                return if frac == 0 {
+                    Self::Infinite { negative }
+                } else {
+                    Self::Nan
+                };
+            }
+            _ => (1, biased_exp - bias),
+        };
+        // Pad the fraction to a whole number of hex digits.
+        let padding = (4 - frac_bits % 4) % 4;
+        Self::Finite {
+            negative,
+            lead,
+            frac: frac << padding,
+            frac_digits: (frac_bits + padding) / 4,
+            exp,
+        }
+    }
+}
+
+impl Display for HexFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Nan => f.write_str("nan"),
+            Self::Infinite { negative } => f.write_str(if negative { "-inf" } else { "inf" }),
+            Self::Finite {
+                negative,
+                lead,
+                mut frac,
+                mut frac_digits,
+                exp,
+            } => {
+                if negative {
+                    f.write_str("-")?;
+                }
+                write!(f, "0x{lead}")?;
+                if frac != 0 {
+                    while frac & 0xf == 0 {
+                        frac >>= 4;
+                        frac_digits -= 1;
+                    }
+                    write!(f, ".{frac:0width$x}", width = frac_digits as usize)?;
+                }
+                write!(f, "p{exp:+}")
+            }
+        }
+    }
+}
+
+fn wide_to_f64(wide: WideNumber) -> f64 {
+    match wide {
+        WideNumber::Signed(v) => v as f64,
+        WideNumber::Unsigned(v) => v as f64,
+        WideNumber::Float(v) => v,
+    }
 }
 
 pub trait Float:
@@ -300,25 +1089,142 @@ pub trait Float:
 
     const NEG_ZERO: Self;
 
-    type Bits: Unsigned;
+    /// Returns `true` if `self` is a power of two, including negative powers
+    /// of two such as `0.5` and subnormal powers of two.
+    ///
+    /// Returns `false` for zero, negative numbers, infinities and NaN.
+    fn is_power_of_two(self) -> bool;
 
-    // @START@ DECL FLOAT
-    // Generated by generate_delegates.py
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// Returns NaN for zero, negative numbers and NaN, and infinity if the
+    /// result is larger than [`MAX_FINITE`](Float::MAX_FINITE).
+    fn next_power_of_two(self) -> Self;
 
-    /// See [`f32::is_nan`].
-    fn is_nan(self) -> bool;
+    /// Returns a key whose integer ordering is the same as the ordering of
+    /// [`total_cmp`](Float::total_cmp).
+    ///
+    /// This can be used to sort floats with `sort_by_key`.
+    fn total_cmp_key(self) -> Self::Bits;
 
-    /// See [`f32::is_infinite`].
-    fn is_infinite(self) -> bool;
+    /// Reverses the byte order of the bits of `self`.
+    ///
+    /// See [`i32::swap_bytes`].
+    fn swap_bytes(self) -> Self;
 
-    /// See [`f32::is_finite`].
-    fn is_finite(self) -> bool;
+    /// Reverses the order of the bits of `self`.
+    ///
+    /// See [`i32::reverse_bits`].
+    fn reverse_bits(self) -> Self;
 
-    /// See [`f32::is_subnormal`].
-    fn is_subnormal(self) -> bool;
+    /// Converts `x` from big endian to the target's endianness.
+    ///
+    /// See [`i32::from_be`].
+    fn from_be(x: Self) -> Self;
 
-    /// See [`f32::is_normal`].
-    fn is_normal(self) -> bool;
+    /// Converts `x` from little endian to the target's endianness.
+    ///
+    /// See [`i32::from_le`].
+    fn from_le(x: Self) -> Self;
+
+    /// Converts `self` to big endian from the target's endianness.
+    ///
+    /// See [`i32::to_be`].
+    fn to_be(self) -> Self;
+
+    /// Converts `self` to little endian from the target's endianness.
+    ///
+    /// See [`i32::to_le`].
+    fn to_le(self) -> Self;
+
+    /// Converts an integer to `Self`, like an `as` cast.
+    ///
+    /// Large integers may be rounded, as they can't all be represented
+    /// exactly, and [`u128`] values that are too large for [`f32`] become
+    /// infinity.
+    fn from_int<I: Integer>(i: I) -> Self;
+
+    /// Linearly interpolates between `self` and `to`, returning `self` when
+    /// `t` is `0` and `to` when `t` is `1`.
+    ///
+    /// This is computed as `self * (1 - t) + to * t`, which is exact at both
+    /// endpoints. `t` outside of `[0, 1]` extrapolates.
+    fn lerp(self, to: Self, t: Self) -> Self;
+
+    /// Same as [`lerp`](Float::lerp), but with `t` clamped to `[0, 1]`.
+    fn lerp_clamped(self, to: Self, t: Self) -> Self;
+
+    /// Computes the sine of `self` in degrees.
+    #[cfg(feature = "std")]
+    fn sin_deg(self) -> Self;
+
+    /// Computes the cosine of `self` in degrees.
+    #[cfg(feature = "std")]
+    fn cos_deg(self) -> Self;
+
+    /// Computes the tangent of `self` in degrees.
+    #[cfg(feature = "std")]
+    fn tan_deg(self) -> Self;
+
+    /// Parses a hexadecimal float like `0x1.8p1`, as in C99 and `%a` in
+    /// `printf`, returning `None` if `s` is not valid.
+    ///
+    /// The syntax is an optional sign, `0x` or `0X`, hex digits with an
+    /// optional `.`, and a required binary exponent like `p-3` or `P+3`. The
+    /// result is exact if it is representable, and is otherwise rounded to the
+    /// nearest value, with ties to even. `inf`, `infinity` and `nan` are also
+    /// accepted, ignoring case.
+    fn from_hex_float(s: &str) -> Option<Self>;
+
+    /// Formats `self` exactly as a hexadecimal float like `0x1.8p+1`, which
+    /// [`from_hex_float`](Float::from_hex_float) parses back to the same
+    /// value.
+    ///
+    /// Subnormals are formatted with a leading `0x0.` and the minimum
+    /// exponent, and infinities as `inf` and `-inf`. NaN is formatted as `nan`,
+    /// so its sign and payload are lost.
+    fn to_hex_float(self) -> impl Display;
+
+    /// See [`f32::next_up`].
+    fn next_up(self) -> Self;
+
+    /// See [`f32::next_down`].
+    fn next_down(self) -> Self;
+
+    /// Returns the next representable value after `self` in the direction of
+    /// `toward`, like `nextafter` in C.
+    ///
+    /// Returns `toward` if it is equal to `self`, so stepping from `-0.0`
+    /// toward `0.0` returns `0.0`. Returns NaN if either argument is NaN.
+    fn next_after(self, toward: Self) -> Self;
+
+    /// Returns the smallest integer power of `base` that is greater than or
+    /// equal to `self`.
+    ///
+    /// Returns NaN if either argument is NaN, if `self <= 0` as there is no
+    /// smallest such power, or if `base <= 1`. If `base` is infinity the
+    /// result is `1` or infinity, and the result is infinity if it would be
+    /// larger than [`MAX_FINITE`](Float::MAX_FINITE).
+    #[cfg(feature = "std")]
+    fn next_power_of(self, base: Self) -> Self;
+
+    // @START@ DECL FLOAT
+    // Generated by generate_delegates.py
+
+    /// See [`f32::is_nan`].
+    fn is_nan(self) -> bool;
+
+    /// See [`f32::is_infinite`].
+    fn is_infinite(self) -> bool;
+
+    /// See [`f32::is_finite`].
+    fn is_finite(self) -> bool;
+
+    /// See [`f32::is_subnormal`].
+    fn is_subnormal(self) -> bool;
+
+    /// See [`f32::is_normal`].
+    fn is_normal(self) -> bool;
 
     /// See [`f32::classify`].
     fn classify(self) -> FpCategory;
@@ -344,12 +1250,6 @@ pub trait Float:
     /// See [`f32::min`].
     fn min(self, other: Self) -> Self;
 
-    /// See [`f32::to_bits`].
-    fn to_bits(self) -> Self::Bits;
-
-    /// See [`f32::from_bits`].
-    fn from_bits(v: Self::Bits) -> Self;
-
     /// See [`f32::total_cmp`].
     fn total_cmp(&self, other: &Self) -> Ordering;
 
@@ -506,8 +1406,53 @@ macro_rules! impl_float {
             one: 1.0,
             min: Self::NEG_INFINITY,
             max: Self::INFINITY,
+            bits: $bits,
             abs: Self::abs,
-            signum: Self::signum
+            signum: Self::signum,
+            saturating_abs: Self::abs,
+            wrapping_abs: Self::abs,
+            checked_recip: |v: Self| Some(v.recip()),
+            to_bits: Self::to_bits,
+            from_bits: Self::from_bits,
+            to_wide: |v| WideNumber::Float(v as f64),
+            try_from_wide: |wide| {
+                let v = wide_to_f64(wide);
+                let res = v as Self;
+                (res.is_finite() || !v.is_finite()).then_some(res)
+            },
+            saturating_from_wide: |wide| {
+                let v = wide_to_f64(wide);
+                let res = v as Self;
+                if res.is_finite() || !v.is_finite() {
+                    res
+                } else if v > 0.0 {
+                    Self::MAX
+                } else {
+                    Self::MIN
+                }
+            },
+            to_u128_key: |v: Self| {
+                const SIGN_MASK: $bits = 1 << (<$bits>::BITS - 1);
+                let bits = v.to_bits();
+                let key = if bits & SIGN_MASK != 0 {
+                    !bits
+                } else {
+                    bits | SIGN_MASK
+                };
+                key as u128
+            },
+            hash_key: |v: Self| {
+                if v.is_nan() {
+                    Self::NAN.to_bits()
+                } else if v == 0.0 {
+                    0
+                } else {
+                    v.to_bits()
+                }
+            },
+            cmp: Self::total_cmp,
+            cmp_magnitude: |a: Self, b: Self| a.abs().partial_cmp(&b.abs()),
+            is_float: true
         );
         impl Float for $ty {
             const RADIX: u32 = Self::RADIX;
@@ -533,7 +1478,160 @@ macro_rules! impl_float {
 
             const NEG_ZERO: Self = -0.0;
 
-            type Bits = $bits;
+            fn is_power_of_two(self) -> bool {
+                const MANTISSA_MASK: $bits = (1 << (<$ty>::MANTISSA_DIGITS - 1)) - 1;
+
+                if self.is_nan() || self <= 0.0 || self.is_infinite() {
+                    return false;
+                }
+
+                let bits = self.to_bits();
+                if self.is_subnormal() {
+                    bits.is_power_of_two()
+                } else {
+                    bits & MANTISSA_MASK == 0
+                }
+            }
+
+            fn next_power_of_two(self) -> Self {
+                const MANTISSA_MASK: $bits = (1 << (<$ty>::MANTISSA_DIGITS - 1)) - 1;
+
+                if self.is_nan() || self <= 0.0 {
+                    return Self::NAN;
+                }
+                if self.is_infinite() || Float::is_power_of_two(self) {
+                    return self;
+                }
+
+                let bits = self.to_bits();
+                if self.is_subnormal() {
+                    // Rounds up to the smallest normal number if needed.
+                    Self::from_bits(bits.next_power_of_two())
+                } else {
+                    // Rounds up to infinity if needed.
+                    Self::from_bits((bits & !MANTISSA_MASK) + MANTISSA_MASK + 1)
+                }
+            }
+
+            fn total_cmp_key(self) -> Self::Bits {
+                const SIGN_MASK: $bits = 1 << (<$bits>::BITS - 1);
+
+                let bits = self.to_bits();
+                if bits & SIGN_MASK == 0 {
+                    bits | SIGN_MASK
+                } else {
+                    !bits
+                }
+            }
+
+            fn swap_bytes(self) -> Self {
+                Self::from_bits(self.to_bits().swap_bytes())
+            }
+
+            fn reverse_bits(self) -> Self {
+                Self::from_bits(self.to_bits().reverse_bits())
+            }
+
+            fn from_be(x: Self) -> Self {
+                Self::from_bits(<$bits>::from_be(x.to_bits()))
+            }
+
+            fn from_le(x: Self) -> Self {
+                Self::from_bits(<$bits>::from_le(x.to_bits()))
+            }
+
+            fn to_be(self) -> Self {
+                Self::from_bits(self.to_bits().to_be())
+            }
+
+            fn to_le(self) -> Self {
+                Self::from_bits(self.to_bits().to_le())
+            }
+
+            fn from_int<I: Integer>(i: I) -> Self {
+                match i.to_wide_integer() {
+                    WideInteger::Signed(v) => v as Self,
+                    WideInteger::Unsigned(v) => v as Self,
+                }
+            }
+
+            fn lerp(self, to: Self, t: Self) -> Self {
+                self * (1.0 - t) + to * t
+            }
+
+            fn lerp_clamped(self, to: Self, t: Self) -> Self {
+                self.lerp(to, t.clamp(0.0, 1.0))
+            }
+
+            #[cfg(feature = "std")]
+            fn sin_deg(self) -> Self {
+                self.to_radians().sin()
+            }
+
+            #[cfg(feature = "std")]
+            fn cos_deg(self) -> Self {
+                self.to_radians().cos()
+            }
+
+            #[cfg(feature = "std")]
+            fn tan_deg(self) -> Self {
+                self.to_radians().tan()
+            }
+
+            fn from_hex_float(s: &str) -> Option<Self> {
+                let (negative, s) = match s.strip_prefix('-') {
+                    Some(s) => (true, s),
+                    None => (false, s.strip_prefix('+').unwrap_or(s)),
+                };
+                let bits =
+                    parse_hex_float_bits(s, Self::MANTISSA_DIGITS, Self::MIN_EXP, Self::MAX_EXP)?;
+                let v = Self::from_bits(bits as $bits);
+                Some(if negative { -v } else { v })
+            }
+
+            fn to_hex_float(self) -> impl Display {
+                HexFloat::new(
+                    self.to_bits() as u64,
+                    <$bits>::BITS,
+                    Self::MANTISSA_DIGITS,
+                    Self::MAX_EXP,
+                )
+            }
+
+            fn next_up(self) -> Self {
+                Self::next_up(self)
+            }
+
+            fn next_down(self) -> Self {
+                Self::next_down(self)
+            }
+
+            fn next_after(self, toward: Self) -> Self {
+                match self.partial_cmp(&toward) {
+                    None => Self::NAN,
+                    Some(Ordering::Equal) => toward,
+                    Some(Ordering::Less) => self.next_up(),
+                    Some(Ordering::Greater) => self.next_down(),
+                }
+            }
+
+            #[cfg(feature = "std")]
+            fn next_power_of(self, base: Self) -> Self {
+                if !(self > 0.0 && base > 1.0) {
+                    return Self::NAN;
+                }
+                if base == Self::INFINITY {
+                    return if self <= 1.0 { 1.0 } else { Self::INFINITY };
+                }
+                let mut exp = (self.ln() / base.ln()).ceil();
+                // Correct for rounding errors in the logarithms.
+                if base.powf(exp - 1.0) >= self {
+                    exp -= 1.0;
+                } else if base.powf(exp) < self {
+                    exp += 1.0;
+                }
+                base.powf(exp)
+            }
 
             // @START@ IMPL FLOAT
             // Generated by generate_delegates.py
@@ -590,14 +1688,6 @@ macro_rules! impl_float {
                 Self::min(self, other)
             }
 
-            fn to_bits(self) -> Self::Bits {
-                Self::to_bits(self)
-            }
-
-            fn from_bits(v: Self::Bits) -> Self {
-                Self::from_bits(v)
-            }
-
             fn total_cmp(&self, other: &Self) -> Ordering {
                 Self::total_cmp(&self, other)
             }
@@ -838,7 +1928,11 @@ pub trait Integer:
     + Octal
     + LowerHex
     + UpperHex
+    + WidenInteger
 {
+    /// Whether `Self` is a signed integer type.
+    const IS_SIGNED: bool;
+
     type Unsigned: Unsigned;
     type Signed: Signed;
 
@@ -853,6 +1947,79 @@ pub trait Integer:
     #[cfg(not(feature = "std"))]
     fn rem_euclid(self, rhs: Self) -> Self;
 
+    /// Returns `(self / rhs, self % rhs)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or if the division overflows.
+    fn div_rem(self, rhs: Self) -> (Self, Self);
+
+    /// Returns `(self.div_euclid(rhs), self.rem_euclid(rhs))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or if the division overflows.
+    fn div_rem_euclid(self, rhs: Self) -> (Self, Self);
+
+    /// Returns `Some((self / rhs, self % rhs))`, or `None` if `rhs` is zero or
+    /// the division overflows.
+    fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)>;
+
+    /// Converts `value` to `Self`, returning `None` if it is out of range.
+    fn checked_from<U: Integer>(value: U) -> Option<Self>;
+
+    /// Converts `self` to `U`, returning `None` if it is out of range.
+    fn checked_into<U: Integer>(self) -> Option<U>;
+
+    /// Converts `value` to `Self` like an `as` cast would, truncating or
+    /// sign-extending as needed.
+    fn wrapping_from<U: Integer>(value: U) -> Self;
+
+    /// Same as [`checked_shl`](Integer::checked_shl), but takes the shift
+    /// amount as `Self`, returning `None` if it is negative or not less than
+    /// the number of bits in `Self`.
+    fn checked_shl_by(self, rhs: Self) -> Option<Self>;
+
+    /// Same as [`checked_shr`](Integer::checked_shr), but takes the shift
+    /// amount as `Self`, returning `None` if it is negative or not less than
+    /// the number of bits in `Self`.
+    fn checked_shr_by(self, rhs: Self) -> Option<Self>;
+
+    /// Returns the absolute value of `self` as an unsigned integer.
+    ///
+    /// This is [`unsigned_abs`](Signed::unsigned_abs) for signed integers and
+    /// the identity for unsigned integers, so it never overflows.
+    fn magnitude(self) -> Self::Unsigned;
+
+    /// Returns the number of decimal digits in `self`, not counting the sign.
+    ///
+    /// `0` has one digit.
+    fn count_digits(self) -> u32;
+
+    /// Rotates the bits of `self` left by `n` if `n` is positive, or right by
+    /// `-n` if `n` is negative.
+    ///
+    /// See [`rotate_left`](Integer::rotate_left) and
+    /// [`rotate_right`](Integer::rotate_right).
+    fn rotate(self, n: i32) -> Self;
+
+    /// Returns the number of bits that differ between `self` and `other`.
+    fn hamming_distance(self, other: Self) -> u32;
+
+    /// Returns the number of ones in the binary representation of `self`.
+    ///
+    /// This is the same as [`count_ones`](Integer::count_ones).
+    fn hamming_weight(self) -> u32;
+
+    /// Parses a decimal integer like [`FromStr`], but clamps out of range
+    /// values to [`MIN`](NumberLike::MIN) or [`MAX`](NumberLike::MAX) instead
+    /// of returning an error. This includes negative values for unsigned
+    /// integers, e.g. `"-1"` is parsed as `0`.
+    ///
+    /// Other errors, such as invalid digits or an empty string, are still
+    /// returned.
+    fn saturating_from_str(s: &str) -> Result<Self, ParseIntError>;
+
     // @START@ DECL INTEGER
     // Generated by generate_delegates.py
 
@@ -1067,27 +2234,61 @@ pub trait Integer:
 }
 
 macro_rules! impl_integer {
-    ($ty:ty, $unsigned:ty, $signed:ty, abs: $abs:expr, signum: $signum:expr) => {
+    (
+        $ty:ty,
+        $unsigned:ty,
+        $signed:ty,
+        abs: $abs:expr,
+        signum: $signum:expr,
+        saturating_abs: $saturating_abs:expr,
+        wrapping_abs: $wrapping_abs:expr,
+        magnitude: $magnitude:expr,
+        wide: $wide:ident,
+        is_signed: $is_signed:expr
+    ) => {
         impl_number!(
             $ty,
             zero: 0,
             one: 1,
             min: Self::MIN,
             max: Self::MAX,
+            bits: $unsigned,
             abs: $abs,
-            signum: $signum
+            signum: $signum,
+            saturating_abs: $saturating_abs,
+            wrapping_abs: $wrapping_abs,
+            checked_recip: |v| Self::checked_div(1, v),
+            to_bits: |v| v as $unsigned,
+            from_bits: |v| v as Self,
+            to_wide: |v: Self| WideNumber::from(v.to_wide_integer()),
+            try_from_wide: integer_try_from_wide,
+            saturating_from_wide: integer_saturating_from_wide,
+            // Flips the sign bit of signed integers, mapping `MIN` to zero.
+            to_u128_key: |v: Self| (v as $unsigned ^ Self::MIN as $unsigned) as u128,
+            hash_key: |v: Self| v,
+            cmp: Ord::cmp,
+            cmp_magnitude: |a: Self, b: Self| Some($magnitude(a).cmp(&$magnitude(b))),
+            is_float: false
         );
+        impl WidenInteger for $ty {
+            fn to_wide_integer(self) -> WideInteger {
+                WideInteger::$wide(self as _)
+            }
+        }
+
         impl Integer for $ty {
+            const IS_SIGNED: bool = $is_signed;
+
             type Unsigned = $unsigned;
             type Signed = $signed;
 
             fn to_unsigned(self) -> Self::Unsigned {
-                #[allow(clippy::useless_transmute)]
+                #[allow(unknown_lints, clippy::useless_transmute, unnecessary_transmutes)]
                 unsafe { transmute::<Self, Self::Unsigned>(self) }
             }
 
             fn to_signed(self) -> Self::Signed {
-                #[allow(clippy::useless_transmute)]
+                #[allow(unknown_lints, clippy::useless_transmute, unnecessary_transmutes)]
                 unsafe { transmute::<Self, Self::Signed>(self) }
             }
 
@@ -1101,6 +2302,79 @@ macro_rules! impl_integer {
                 Self::rem_euclid(self, rhs)
             }
 
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                (self / rhs, self % rhs)
+            }
+
+            fn div_rem_euclid(self, rhs: Self) -> (Self, Self) {
+                (Self::div_euclid(self, rhs), Self::rem_euclid(self, rhs))
+            }
+
+            fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+                Some((Self::checked_div(self, rhs)?, Self::checked_rem(self, rhs)?))
+            }
+
+            fn checked_from<U: Integer>(value: U) -> Option<Self> {
+                integer_try_from_wide(value.to_wide())
+            }
+
+            fn checked_into<U: Integer>(self) -> Option<U> {
+                U::checked_from(self)
+            }
+
+            fn wrapping_from<U: Integer>(value: U) -> Self {
+                // Widening to 128 bits sign-extends signed values, so the
+                // truncating cast matches a direct `as` cast from `U`.
+                Self::wrapping_from_wide(value.to_wide())
+            }
+
+            fn checked_shl_by(self, rhs: Self) -> Option<Self> {
+                Self::checked_shl(self, rhs.try_into().ok()?)
+            }
+
+            fn checked_shr_by(self, rhs: Self) -> Option<Self> {
+                Self::checked_shr(self, rhs.try_into().ok()?)
+            }
+
+            fn magnitude(self) -> Self::Unsigned {
+                $magnitude(self)
+            }
+
+            fn count_digits(self) -> u32 {
+                self.magnitude().checked_ilog10().map_or(1, |digits| digits + 1)
+            }
+
+            fn rotate(self, n: i32) -> Self {
+                // Rotating right by `k` is the same as rotating left by `BITS - k`.
+                Self::rotate_left(self, n.rem_euclid(Self::BITS as i32) as u32)
+            }
+
+            fn hamming_distance(self, other: Self) -> u32 {
+                (self ^ other).count_ones()
+            }
+
+            fn hamming_weight(self) -> u32 {
+                self.count_ones()
+            }
+
+            fn saturating_from_str(s: &str) -> Result<Self, ParseIntError> {
+                match Self::from_str_radix(s, 10) {
+                    Err(e) if *e.kind() == IntErrorKind::PosOverflow => Ok(Self::MAX),
+                    Err(e) if *e.kind() == IntErrorKind::NegOverflow => Ok(Self::MIN),
+                    // Unsigned parsing rejects any minus sign as an invalid
+                    // digit, even though the value is just out of range.
+                    Err(_)
+                        if Self::MIN == 0
+                            && s.strip_prefix('-').is_some_and(|digits| {
+                                !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+                            }) =>
+                    {
+                        Ok(Self::MIN)
+                    }
+                    res => res,
+                }
+            }
+
             // @START@ IMPL INTEGER
             // Generated by generate_delegates.py
 
@@ -1370,6 +2644,13 @@ macro_rules! impl_integer {
 }
 
 pub trait Unsigned: Integer + From<u8> {
+    /// Returns only the lowest `n` bits of `self`, with all other bits set to
+    /// zero.
+    ///
+    /// Returns `0` if `n` is zero and `self` if `n` is at least the number of
+    /// bits in `Self`.
+    fn low_bits(self, n: u32) -> Self;
+
     // @START@ DECL UNSIGNED
     // Generated by generate_delegates.py
 
@@ -1413,9 +2694,18 @@ macro_rules! impl_unsigned {
             Self,
             $signed,
             abs: |v| v,
-            signum: |v| (v > 0) as Self
+            signum: |v| (v > 0) as Self,
+            saturating_abs: |v| v,
+            wrapping_abs: |v| v,
+            magnitude: |v| v,
+            wide: Unsigned,
+            is_signed: false
         );
         impl Unsigned for $ty {
+            fn low_bits(self, n: u32) -> Self {
+                self & !Self::MAX.unbounded_shl(n)
+            }
+
             // @START@ IMPL UNSIGNED
             // Generated by generate_delegates.py
 
@@ -1461,6 +2751,13 @@ macro_rules! impl_unsigned {
 
             // @END@ IMPL UNSIGNED
         }
+        impl MixedSignArithmetic for $ty {
+            type OtherSign = $signed;
+
+            fn checked_add_other(self, rhs: Self::OtherSign) -> Option<Self> {
+                Self::checked_add_signed(self, rhs)
+            }
+        }
     };
 }
 
@@ -1472,6 +2769,13 @@ impl_unsigned!(u128, i128);
 impl_unsigned!(usize, isize);
 
 pub trait Signed: Integer + Neg + From<i8> {
+    /// Sign-extends the lowest `from_bits` bits of `self`, treating bit
+    /// `from_bits - 1` as the sign bit and ignoring all higher bits.
+    ///
+    /// Returns `0` if `from_bits` is zero and `self` if `from_bits` is at
+    /// least the number of bits in `Self`.
+    fn sign_extend(self, from_bits: u32) -> Self;
+
     // @START@ DECL SIGNED
     // Generated by generate_delegates.py
 
@@ -1496,18 +2800,12 @@ pub trait Signed: Integer + Neg + From<i8> {
     /// See [`i32::saturating_neg`].
     fn saturating_neg(self) -> Self;
 
-    /// See [`i32::saturating_abs`].
-    fn saturating_abs(self) -> Self;
-
     /// See [`i32::wrapping_add_unsigned`].
     fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self;
 
     /// See [`i32::wrapping_sub_unsigned`].
     fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
 
-    /// See [`i32::wrapping_abs`].
-    fn wrapping_abs(self) -> Self;
-
     /// See [`i32::unsigned_abs`].
     fn unsigned_abs(self) -> Self::Unsigned;
 
@@ -1537,8 +2835,30 @@ pub trait Signed: Integer + Neg + From<i8> {
 
 macro_rules! impl_signed {
     ($ty:ty, $unsigned:ty) => {
-        impl_integer!($ty, $unsigned, Self, abs: Self::abs, signum: Self::signum);
+        impl_integer!(
+            $ty,
+            $unsigned,
+            Self,
+            abs: Self::abs,
+            signum: Self::signum,
+            saturating_abs: Self::saturating_abs,
+            wrapping_abs: Self::wrapping_abs,
+            magnitude: Self::unsigned_abs,
+            wide: Signed,
+            is_signed: true
+        );
         impl Signed for $ty {
+            fn sign_extend(self, from_bits: u32) -> Self {
+                match from_bits {
+                    0 => 0,
+                    ..Self::BITS => {
+                        let shift = Self::BITS - from_bits;
+                        (self << shift) >> shift
+                    }
+                    _ => self,
+                }
+            }
+
             // @START@ IMPL SIGNED
             // Generated by generate_delegates.py
 
@@ -1570,10 +2890,6 @@ macro_rules! impl_signed {
                 Self::saturating_neg(self)
             }
 
-            fn saturating_abs(self) -> Self {
-                Self::saturating_abs(self)
-            }
-
             fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self {
                 Self::wrapping_add_unsigned(self, rhs)
             }
@@ -1582,10 +2898,6 @@ macro_rules! impl_signed {
                 Self::wrapping_sub_unsigned(self, rhs)
             }
 
-            fn wrapping_abs(self) -> Self {
-                Self::wrapping_abs(self)
-            }
-
             fn unsigned_abs(self) -> Self::Unsigned {
                 Self::unsigned_abs(self)
             }
@@ -1620,6 +2932,13 @@ macro_rules! impl_signed {
 
             // @END@ IMPL SIGNED
         }
+        impl MixedSignArithmetic for $ty {
+            type OtherSign = $unsigned;
+
+            fn checked_add_other(self, rhs: Self::OtherSign) -> Option<Self> {
+                Self::checked_add_unsigned(self, rhs)
+            }
+        }
     };
 }
 
@@ -1630,35 +2949,370 @@ impl_signed!(i64, u64);
 impl_signed!(i128, u128);
 impl_signed!(isize, usize);
 
-#[cfg(test)]
-mod test {
-    use super::*;
+/// Arithmetic between an [`Integer`] and the integer of the same size with the
+/// other signedness.
+///
+/// This unifies the `*_signed` methods of [`Unsigned`] and the `*_unsigned`
+/// methods of [`Signed`].
+pub trait MixedSignArithmetic: Integer {
+    /// [`Signed`](Integer::Signed) for unsigned integers and
+    /// [`Unsigned`](Integer::Unsigned) for signed integers.
+    type OtherSign: Integer;
+
+    /// See [`u32::checked_add_signed`] and [`i32::checked_add_unsigned`].
+    fn checked_add_other(self, rhs: Self::OtherSign) -> Option<Self>;
+}
 
-    #[test]
-    fn test_subnormal_consts() {
-        assert_eq!(f32::MIN_POSITIVE_SUBNORMAL, f32::from_bits(1));
-        assert_eq!(f32::MAX_NEGATIVE_SUBNORMAL, -f32::from_bits(1));
-        assert_eq!(f64::MIN_POSITIVE_SUBNORMAL, f64::from_bits(1));
-        assert_eq!(f64::MAX_NEGATIVE_SUBNORMAL, -f64::from_bits(1));
-    }
+/// Converts a [`Number`] to another [`Number`] type, returning `None` if the
+/// value is out of range for `U`.
+///
+/// Floats are truncated towards zero when converted to integers, and NaN and
+/// infinities are never in range for integers. Conversions to floats go
+/// through [`f64`], so large 64-bit and 128-bit integers may lose precision,
+/// but they will never be out of range unless they overflow to infinity.
+pub fn num_cast<T: Number, U: Number>(v: T) -> Option<U> {
+    U::try_from_wide(v.to_wide())
+}
 
-    #[cfg(feature = "std")]
-    #[test]
-    fn test_float_floor() {
-        assert_eq!(<f64 as Float>::floor(1.5), 1.0);
+/// Samples a uniformly random valid value of a [`NumberLike`].
+///
+/// For floats every bit pattern is equally likely, so this can return NaNs,
+/// infinities and subnormals.
+#[cfg(feature = "rand")]
+pub fn sample<T: NumberLike, R: rand::Rng + ?Sized>(rng: &mut R) -> T {
+    // Types with a non-negative range, i.e. `bool`, `char` and the unsigned
+    // integers, are sampled uniformly within their range. Everything else is
+    // filled from random bytes. Invalid values, such as surrogates for `char`,
+    // are retried.
+    let min = num_cast::<_, u128>(T::MIN.to_underlying());
+    let max = num_cast::<_, u128>(T::MAX.to_underlying());
+    loop {
+        let underlying = match min.zip(max) {
+            Some((min, max)) => num_cast(sample_uniform(rng, min, max)),
+            None => {
+                let mut underlying = T::Underlying::ZERO;
+                rng.fill_bytes(underlying.as_mut_bytes().as_mut_slice());
+                Some(underlying)
+            }
+        };
+        if let Some(v) = underlying.and_then(T::try_from_underlying) {
+            return v;
+        }
     }
+}
 
-    #[test]
-    fn test_euclid_core() {
-        fn test_int<T: Integer>(a: T, b: T) -> (T, T) {
-            (a.div_euclid(b), a.rem_euclid(b))
+/// Samples a uniformly random [`Integer`] in the inclusive range `lo..=hi`.
+///
+/// # Panics
+///
+/// Panics if `lo > hi`.
+#[cfg(feature = "rand")]
+pub fn sample_uniform<T: Integer, R: rand::Rng + ?Sized>(rng: &mut R, lo: T, hi: T) -> T {
+    assert!(lo <= hi, "cannot sample empty range");
+
+    let max = T::Unsigned::MAX;
+    let range = hi.abs_diff(lo);
+    let offset = loop {
+        let mut bits = T::Unsigned::ZERO;
+        rng.fill_bytes(bits.as_mut_bytes().as_mut_slice());
+        if range == max {
+            break bits;
         }
 
-        assert_eq!(test_int(-7, 4), (-2, 1));
-    }
+        // Reject the top `(max + 1) % (range + 1)` values to avoid bias. None
+        // of these operations can overflow.
+        let n = range.wrapping_add(T::Unsigned::ONE);
+        let rejected = max
+            .wrapping_rem(n)
+            .wrapping_add(T::Unsigned::ONE)
+            .wrapping_rem(n);
+        if bits <= max.wrapping_sub(rejected) {
+            break bits.wrapping_rem(n);
+        }
+    };
 
-    #[cfg(feature = "std")]
-    #[test]
+    // `lo + offset <= hi`, so adding in two's complement and converting back
+    // gives the result for signed integers as well.
+    T::wrapping_from(lo.to_unsigned().wrapping_add(offset))
+}
+
+/// Parses a [`NumberLike`] from a string.
+///
+/// Same as [`str::parse`], but without the need for a turbofish.
+pub fn parse<T: NumberLike>(s: &str) -> Result<T, T::Err> {
+    s.parse()
+}
+
+/// Parses an [`Integer`] from a string in the given radix.
+///
+/// See [`i32::from_str_radix`].
+pub fn parse_radix<T: Integer>(s: &str, radix: u32) -> Result<T, ParseIntError> {
+    T::from_str_radix(s, radix)
+}
+
+/// An error returned by [`parse_array`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseArrayError<E> {
+    /// The string did not contain the expected number of items.
+    WrongLength { expected: usize, found: usize },
+    /// The item at `index` failed to parse.
+    Parse { index: usize, error: E },
+}
+
+impl<E: Display> Display for ParseArrayError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, found } => {
+                write!(f, "expected {expected} items, found {found}")
+            }
+            Self::Parse { index, error } => write!(f, "invalid item at index {index}: {error}"),
+        }
+    }
+}
+
+impl<E: Error> Error for ParseArrayError<E> {}
+
+/// Parses `N` [`NumberLike`]s separated by `sep` from a string.
+///
+/// Items are not trimmed, so `"1, 2"` fails to parse with `sep = ','`.
+pub fn parse_array<T: NumberLike, const N: usize>(
+    s: &str,
+    sep: char,
+) -> Result<[T; N], ParseArrayError<T::Err>> {
+    let mut array = [T::default(); N];
+    let mut found = 0;
+    // An empty string contains no items rather than a single empty item.
+    let items = (!s.is_empty()).then(|| s.split(sep)).into_iter().flatten();
+    for item in items {
+        if let Some(slot) = array.get_mut(found) {
+            *slot = item.parse().map_err(|error| ParseArrayError::Parse {
+                index: found,
+                error,
+            })?;
+        }
+        found += 1;
+    }
+
+    if found == N {
+        Ok(array)
+    } else {
+        Err(ParseArrayError::WrongLength { expected: N, found })
+    }
+}
+
+/// Sums the items of an iterator, starting from [`ZERO`](Number::ZERO).
+///
+/// Same as [`Iterator::sum`], but without the need for a turbofish.
+pub fn sum<T: Number, I: IntoIterator<Item = T>>(iter: I) -> T {
+    iter.into_iter().fold(T::ZERO, |mut acc, v| {
+        acc += v;
+        acc
+    })
+}
+
+/// Multiplies the items of an iterator, starting from [`ONE`](Number::ONE).
+///
+/// Same as [`Iterator::product`], but without the need for a turbofish.
+pub fn product<T: Number, I: IntoIterator<Item = T>>(iter: I) -> T {
+    iter.into_iter().fold(T::ONE, |mut acc, v| {
+        acc *= v;
+        acc
+    })
+}
+
+/// Sums the items of an iterator, returning `None` as soon as the sum
+/// overflows.
+pub fn checked_sum<T: Integer, I: IntoIterator<Item = T>>(iter: I) -> Option<T> {
+    iter.into_iter()
+        .try_fold(T::ZERO, |acc, v| acc.checked_add(v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_subnormal_consts() {
+        assert_eq!(f32::MIN_POSITIVE_SUBNORMAL, f32::from_bits(1));
+        assert_eq!(f32::MAX_NEGATIVE_SUBNORMAL, -f32::from_bits(1));
+        assert_eq!(f64::MIN_POSITIVE_SUBNORMAL, f64::from_bits(1));
+        assert_eq!(f64::MAX_NEGATIVE_SUBNORMAL, -f64::from_bits(1));
+    }
+
+    #[test]
+    fn test_float_power_of_two() {
+        fn test_is_power_of_two<T: Float>(a: T) -> bool {
+            a.is_power_of_two()
+        }
+
+        fn test_next_power_of_two<T: Float>(a: T) -> T {
+            a.next_power_of_two()
+        }
+
+        assert!(test_is_power_of_two(8.0f64));
+        assert!(test_is_power_of_two(0.25f32));
+        assert!(test_is_power_of_two(f64::MIN_POSITIVE_SUBNORMAL));
+        assert!(test_is_power_of_two(f32::MIN_POSITIVE_NORMAL));
+        assert!(!test_is_power_of_two(6.0f64));
+        assert!(!test_is_power_of_two(f32::MIN_POSITIVE_SUBNORMAL * 3.0));
+        assert!(!test_is_power_of_two(0.0f64));
+        assert!(!test_is_power_of_two(-8.0f64));
+        assert!(!test_is_power_of_two(f64::INFINITY));
+        assert!(!test_is_power_of_two(f64::NAN));
+
+        assert_eq!(test_next_power_of_two(6.0f64), 8.0);
+        assert_eq!(test_next_power_of_two(8.0f64), 8.0);
+        assert_eq!(test_next_power_of_two(0.3f32), 0.5);
+        assert_eq!(
+            test_next_power_of_two(f64::MIN_POSITIVE_SUBNORMAL * 3.0),
+            f64::MIN_POSITIVE_SUBNORMAL * 4.0
+        );
+        assert_eq!(
+            test_next_power_of_two(f32::MIN_POSITIVE_NORMAL - f32::MIN_POSITIVE_SUBNORMAL),
+            f32::MIN_POSITIVE_NORMAL
+        );
+        assert_eq!(test_next_power_of_two(f64::MAX_FINITE), f64::INFINITY);
+        assert_eq!(test_next_power_of_two(f64::INFINITY), f64::INFINITY);
+        assert!(test_next_power_of_two(0.0f64).is_nan());
+        assert!(test_next_power_of_two(-1.0f64).is_nan());
+        assert!(test_next_power_of_two(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_total_cmp_key() {
+        fn sort_by_key<T: Float>(v: &mut [T]) {
+            v.sort_unstable_by_key(|v| v.total_cmp_key());
+        }
+
+        let values = [
+            3.0,
+            -f64::NAN,
+            -0.0,
+            f64::NAN,
+            f64::NEG_INFINITY,
+            1.0,
+            0.0,
+            -1.0,
+            f64::MIN_POSITIVE_SUBNORMAL,
+            f64::INFINITY,
+            -2.5,
+        ];
+        let mut by_key = values;
+        sort_by_key(&mut by_key);
+        let mut by_total_cmp = values;
+        by_total_cmp.sort_unstable_by(f64::total_cmp);
+        assert_eq!(by_key.map(f64::to_bits), by_total_cmp.map(f64::to_bits));
+    }
+
+    #[test]
+    fn test_float_swap_bytes() {
+        fn test_swap_bytes<T: Float>(a: T) -> T {
+            a.swap_bytes()
+        }
+
+        for v in [
+            0.0,
+            -1.5,
+            f32::MAX_FINITE,
+            f32::MIN_POSITIVE_SUBNORMAL,
+            f32::NAN,
+        ] {
+            assert_eq!(test_swap_bytes(test_swap_bytes(v)).to_bits(), v.to_bits());
+            assert_eq!(test_swap_bytes(v).to_bits(), v.to_bits().swap_bytes());
+        }
+        assert_eq!(
+            <f64 as Float>::to_be(1.5).to_ne_bytes(),
+            NumberLike::to_be_bytes(1.5f64)
+        );
+        assert_eq!(
+            <f64 as Float>::to_le(1.5).to_ne_bytes(),
+            NumberLike::to_le_bytes(1.5f64)
+        );
+        assert_eq!(<f32 as Float>::from_be(<f32 as Float>::to_be(1.5)), 1.5);
+        assert_eq!(<f32 as Float>::from_le(<f32 as Float>::to_le(1.5)), 1.5);
+    }
+
+    #[test]
+    fn test_float_reverse_bits() {
+        fn test_reverse_bits<T: Float>(a: T) -> T {
+            a.reverse_bits()
+        }
+
+        for v in [
+            0.0,
+            -1.5,
+            f64::MAX_FINITE,
+            f64::MIN_POSITIVE_SUBNORMAL,
+            f64::NAN,
+        ] {
+            assert_eq!(
+                test_reverse_bits(test_reverse_bits(v)).to_bits(),
+                v.to_bits()
+            );
+        }
+        assert_eq!(test_reverse_bits(1.0f32).to_bits(), 0x0000_01fc);
+        assert_eq!(test_reverse_bits(-0.0f32).to_bits(), 1);
+    }
+
+    #[test]
+    fn test_float_from_int() {
+        fn test_from_int<T: Float, I: Integer>(i: I) -> T {
+            T::from_int(i)
+        }
+
+        assert_eq!(test_from_int::<f64, _>(5u8), 5.0);
+        assert_eq!(test_from_int::<f64, _>(-5i128), -5.0);
+        assert_eq!(test_from_int::<f32, _>(16_777_217i32), 16_777_216.0);
+        assert_eq!(test_from_int::<f32, _>(u64::MAX), u64::MAX as f32);
+        assert_eq!(test_from_int::<f32, _>(u128::MAX), f32::INFINITY);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_float_floor() {
+        assert_eq!(<f64 as Float>::floor(1.5), 1.0);
+    }
+
+    #[test]
+    fn test_euclid_core() {
+        fn test_int<T: Integer>(a: T, b: T) -> (T, T) {
+            (a.div_euclid(b), a.rem_euclid(b))
+        }
+
+        assert_eq!(test_int(-7, 4), (-2, 1));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        fn test_div_rem<T: Integer>(a: T, b: T) -> (T, T) {
+            a.div_rem(b)
+        }
+
+        fn test_div_rem_euclid<T: Integer>(a: T, b: T) -> (T, T) {
+            a.div_rem_euclid(b)
+        }
+
+        assert_eq!(test_div_rem(17i32, 5), (3, 2));
+        assert_eq!(test_div_rem(-7i32, 4), (-1, -3));
+        assert_eq!(test_div_rem(17u8, 5), (3, 2));
+        assert_eq!(test_div_rem_euclid(-7i32, 4), (-2, 1));
+        assert_eq!(test_div_rem_euclid(7u32, 4), (1, 3));
+    }
+
+    #[test]
+    fn test_checked_div_rem() {
+        fn test_checked_div_rem<T: Integer>(a: T, b: T) -> Option<(T, T)> {
+            a.checked_div_rem(b)
+        }
+
+        assert_eq!(test_checked_div_rem(17i32, 5), Some((3, 2)));
+        assert_eq!(test_checked_div_rem(17u32, 0), None);
+        assert_eq!(test_checked_div_rem(i8::MIN, -1), None);
+        assert_eq!(test_checked_div_rem(i8::MIN, 1), Some((i8::MIN, 0)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
     fn test_euclid_std() {
         fn test_num<T: Number>(a: T, b: T) -> (T, T) {
             (a.div_euclid(b), a.rem_euclid(b))
@@ -1704,4 +3358,1188 @@ mod test {
 
         assert!(test_signum(f64::NAN).is_nan());
     }
+
+    #[test]
+    fn test_zeroed() {
+        fn test_zeroed<T: Number>() -> T {
+            T::zeroed()
+        }
+
+        assert_eq!(test_zeroed::<i32>(), 0);
+        assert_eq!(test_zeroed::<u128>(), 0);
+        assert_eq!(test_zeroed::<f64>(), 0.0);
+        assert!(test_zeroed::<f64>().is_sign_positive());
+    }
+
+    #[test]
+    fn test_signum_i8() {
+        fn test_signum_i8<T: Number>(a: T) -> i8 {
+            a.signum_i8()
+        }
+
+        assert_eq!(test_signum_i8(123u32), 1);
+        assert_eq!(test_signum_i8(0u32), 0);
+        assert_eq!(test_signum_i8(-123i32), -1);
+        assert_eq!(test_signum_i8(0i32), 0);
+        assert_eq!(test_signum_i8(-0.5), -1);
+        assert_eq!(test_signum_i8(-0.0), 0);
+        assert_eq!(test_signum_i8(f64::INFINITY), 1);
+        assert_eq!(test_signum_i8(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_to_bool() {
+        fn test_to_bool<T: Number>(a: T) -> bool {
+            a.to_bool()
+        }
+
+        assert!(!test_to_bool(0u8));
+        assert!(test_to_bool(2u8));
+        assert!(test_to_bool(-1i32));
+        assert!(!test_to_bool(0.0f64));
+        assert!(!test_to_bool(-0.0f64));
+        assert!(test_to_bool(0.5f32));
+        assert!(test_to_bool(f64::NAN));
+        assert!(test_to_bool(i32::from(true)));
+    }
+
+    #[test]
+    fn test_midpoint() {
+        fn test_midpoint<T: Number>(a: T, b: T) -> T {
+            a.midpoint(b)
+        }
+
+        assert_eq!(test_midpoint(2u8, 4), 3);
+        assert_eq!(test_midpoint(u8::MAX, u8::MAX - 2), u8::MAX - 1);
+        assert_eq!(test_midpoint(-3i32, 0), -1);
+        assert_eq!(test_midpoint(i64::MIN, i64::MAX), 0);
+        assert_eq!(test_midpoint(1.0, 2.0), 1.5);
+        assert_eq!(test_midpoint(f64::MAX, f64::MAX), f64::MAX);
+    }
+
+    #[test]
+    fn test_num_cast() {
+        assert_eq!(num_cast::<u64, u32>(u64::MAX), None);
+        assert_eq!(num_cast::<i32, i64>(5), Some(5));
+        assert_eq!(num_cast::<i32, u8>(-1), None);
+        assert_eq!(num_cast::<u128, i8>(127), Some(127));
+
+        assert_eq!(num_cast::<f64, u8>(255.9), Some(255));
+        assert_eq!(num_cast::<f64, u8>(256.0), None);
+        assert_eq!(num_cast::<f64, i8>(-128.5), Some(-128));
+        assert_eq!(num_cast::<f64, i8>(-129.0), None);
+        assert_eq!(num_cast::<f32, u128>(f32::MAX), Some(f32::MAX as u128));
+        assert_eq!(num_cast::<f64, i128>(i128::MIN as f64), Some(i128::MIN));
+        assert_eq!(num_cast::<f64, u128>(u128::MAX as f64), None);
+        assert_eq!(num_cast::<f64, i32>(f64::NAN), None);
+        assert_eq!(num_cast::<f64, i32>(f64::INFINITY), None);
+
+        assert_eq!(num_cast::<u64, f64>(u64::MAX), Some(u64::MAX as f64));
+        assert_eq!(num_cast::<u128, f32>(u128::MAX), None);
+        assert_eq!(num_cast::<f64, f32>(1e300), None);
+        assert_eq!(num_cast::<f64, f32>(f64::INFINITY), Some(f32::INFINITY));
+        assert!(num_cast::<f64, f32>(f64::NAN).unwrap().is_nan());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            let v: bool = sample(&mut rng);
+            seen[v as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+
+        // Both below and above `u16::MAX`, which the restricted range of
+        // `char` must not cut off.
+        let mut seen = [false; 2];
+        for _ in 0..1000 {
+            let v: char = sample(&mut rng);
+            seen[(v as u32 > 0xffff) as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+
+        // Both halves of the range.
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            let v: u8 = sample(&mut rng);
+            seen[(v >= 0x80) as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+
+        // Both signs, and magnitudes both below and above one.
+        let mut seen = [[false; 2]; 2];
+        for _ in 0..100 {
+            let v: f64 = sample(&mut rng);
+            if !v.is_nan() {
+                seen[v.is_sign_negative() as usize][(v.abs() >= 1.0) as usize] = true;
+            }
+        }
+        assert_eq!(seen, [[true; 2]; 2]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_uniform() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        // Every value in the range is reachable, including the endpoints.
+        let mut seen = [false; 11];
+        for _ in 0..200 {
+            let v = sample_uniform(&mut rng, 10u8, 20);
+            seen[usize::from(v - 10)] = true;
+        }
+        assert_eq!(seen, [true; 11]);
+
+        let mut seen = [false; 11];
+        for _ in 0..200 {
+            let v = sample_uniform(&mut rng, -20i8, -10);
+            assert!((-20..=-10).contains(&v), "{v}");
+            seen[(v + 20) as usize] = true;
+        }
+        assert_eq!(seen, [true; 11]);
+
+        // Ranges spanning zero and the full range of the type.
+        let mut seen = [false; 3];
+        for _ in 0..200 {
+            let v = sample_uniform(&mut rng, -1i64, 1);
+            assert!((-1..=1).contains(&v), "{v}");
+            seen[(v + 1) as usize] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            let v = sample_uniform(&mut rng, i128::MIN, i128::MAX);
+            seen[(v >= 0) as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+
+        assert_eq!(sample_uniform(&mut rng, 5u32, 5), 5);
+        assert_eq!(sample_uniform(&mut rng, i8::MIN, i8::MIN), i8::MIN);
+    }
+
+    #[test]
+    fn test_saturating_cast() {
+        fn test_saturating_cast<T: Number, U: Number>(v: T) -> U {
+            v.saturating_cast()
+        }
+
+        assert_eq!(test_saturating_cast::<_, u8>(300i32), 255);
+        assert_eq!(test_saturating_cast::<_, u8>(-5i32), 0);
+        assert_eq!(test_saturating_cast::<_, u8>(42i32), 42);
+        assert_eq!(test_saturating_cast::<_, i8>(u128::MAX), i8::MAX);
+        assert_eq!(test_saturating_cast::<_, u64>(i128::MIN), 0);
+
+        assert_eq!(test_saturating_cast::<_, i32>(1e40f64), i32::MAX);
+        assert_eq!(test_saturating_cast::<_, i32>(-1e40f64), i32::MIN);
+        assert_eq!(test_saturating_cast::<_, i32>(-1.5f64), -1);
+        assert_eq!(test_saturating_cast::<_, u32>(f32::NEG_INFINITY), 0);
+        assert_eq!(test_saturating_cast::<_, u32>(f32::NAN), 0);
+
+        assert_eq!(test_saturating_cast::<_, f32>(1e300f64), f32::MAX);
+        assert_eq!(test_saturating_cast::<_, f32>(-1e300f64), f32::MIN);
+        assert_eq!(test_saturating_cast::<_, f32>(u128::MAX), f32::MAX);
+        assert_eq!(test_saturating_cast::<_, f32>(f64::INFINITY), f32::INFINITY);
+        assert!(test_saturating_cast::<_, f32>(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_parse() {
+        fn test_parse<T: Integer>(s: &str) -> Option<T> {
+            parse(s).ok()
+        }
+
+        assert_eq!(test_parse::<i32>("42"), Some(42));
+        assert_eq!(test_parse::<u8>("-1"), None);
+        assert_eq!(parse::<f64>("1.5"), Ok(1.5));
+        assert_eq!(parse::<bool>("true"), Ok(true));
+    }
+
+    #[test]
+    fn test_parse_radix() {
+        assert_eq!(parse_radix::<u8>("ff", 16), Ok(255));
+        assert!(parse_radix::<u8>("100", 16).is_err());
+    }
+
+    #[test]
+    fn test_checked_from() {
+        fn test_checked_from<T: Integer, U: Integer>(v: U) -> Option<T> {
+            T::checked_from(v)
+        }
+
+        fn test_checked_into<T: Integer, U: Integer>(v: T) -> Option<U> {
+            v.checked_into()
+        }
+
+        assert_eq!(test_checked_from::<u8, _>(300i32), None);
+        assert_eq!(test_checked_from::<u8, _>(200i32), Some(200));
+        assert_eq!(test_checked_from::<u8, _>(-1i32), None);
+        assert_eq!(test_checked_from::<i128, _>(u128::MAX), None);
+        assert_eq!(test_checked_from::<u128, _>(u128::MAX), Some(u128::MAX));
+        assert_eq!(test_checked_into::<_, i8>(-128i64), Some(-128));
+        assert_eq!(test_checked_into::<_, i8>(128u16), None);
+    }
+
+    #[test]
+    fn test_wrapping_from() {
+        fn test_wrapping_from<T: Integer, U: Integer>(v: U) -> T {
+            T::wrapping_from(v)
+        }
+
+        assert_eq!(test_wrapping_from::<u8, _>(300i32), 44);
+        assert_eq!(test_wrapping_from::<i8, _>(200u32), -56);
+        assert_eq!(test_wrapping_from::<u32, _>(-1i8), u32::MAX);
+        assert_eq!(test_wrapping_from::<i64, _>(u64::MAX), -1);
+        assert_eq!(test_wrapping_from::<u128, _>(-2i16), u128::MAX - 1);
+        assert_eq!(test_wrapping_from::<i16, _>(234u8), 234);
+    }
+
+    #[test]
+    fn test_byte_array_size() {
+        fn test_byte_array_size<T: NumberLike>() {
+            assert_eq!(size_of::<T::ByteArray>(), size_of::<T>());
+            assert_eq!(<T::ByteArray as Array>::N, size_of::<T>());
+            assert_eq!(
+                size_of::<T::ByteArray>(),
+                size_of::<<T::Underlying as NumberLike>::ByteArray>()
+            );
+        }
+
+        test_byte_array_size::<bool>();
+        test_byte_array_size::<char>();
+        test_byte_array_size::<f32>();
+        test_byte_array_size::<f64>();
+        test_byte_array_size::<u8>();
+        test_byte_array_size::<u16>();
+        test_byte_array_size::<u32>();
+        test_byte_array_size::<u64>();
+        test_byte_array_size::<u128>();
+        test_byte_array_size::<usize>();
+        test_byte_array_size::<i8>();
+        test_byte_array_size::<i16>();
+        test_byte_array_size::<i32>();
+        test_byte_array_size::<i64>();
+        test_byte_array_size::<i128>();
+        test_byte_array_size::<isize>();
+    }
+
+    #[test]
+    fn test_try_from_bytes_validation() {
+        fn roundtrip<T, U>(v: U) -> [Option<T>; 4]
+        where
+            T: NumberLike<Underlying = U, ByteArray = U::ByteArray>,
+            U: Number,
+        {
+            [
+                T::try_from_bytes(v.to_bytes()),
+                T::try_from_be_bytes(v.to_be_bytes()),
+                T::try_from_le_bytes(v.to_le_bytes()),
+                T::try_from_ne_bytes(v.to_ne_bytes()),
+            ]
+        }
+
+        for v in 0..=u8::MAX {
+            let expected = match v {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            };
+            assert_eq!(roundtrip::<bool, _>(v), [expected; 4]);
+        }
+
+        let boundaries = [0, 0x7f, 0xd7ff, 0xd800, 0xdbff, 0xdc00, 0xdfff, 0xe000];
+        let boundaries = boundaries.into_iter().chain([0x10ffff, 0x110000, u32::MAX]);
+        // Miri is too slow to check this many values.
+        let step = if cfg!(miri) { 0x100_0001 } else { 0x1001 };
+        for v in boundaries.chain((0..=u32::MAX).step_by(step)) {
+            assert_eq!(roundtrip::<char, _>(v), [char::from_u32(v); 4], "{v:#x}");
+        }
+
+        let bytes = [0x00, 0xd8, 0x00, 0x00];
+        assert_eq!(char::try_from_le_bytes(bytes), None);
+        assert_eq!(char::try_from_be_bytes(bytes), None);
+        assert_eq!(char::try_from_ne_bytes(bytes), None);
+        assert_eq!(char::try_from_bytes(bytes), None);
+    }
+
+    #[test]
+    fn test_write_bytes() {
+        fn test_write_be_bytes<T: NumberLike>(v: T, out: &mut T::ByteArray) {
+            v.write_be_bytes(out);
+        }
+
+        fn test_write_le_bytes<T: NumberLike>(v: T, out: &mut T::ByteArray) {
+            v.write_le_bytes(out);
+        }
+
+        fn test_write_ne_bytes<T: NumberLike>(v: T, out: &mut T::ByteArray) {
+            v.write_ne_bytes(out);
+        }
+
+        let v = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128;
+        let mut out = [0; 16];
+        test_write_be_bytes(v, &mut out);
+        assert_eq!(out, v.to_be_bytes());
+        test_write_le_bytes(v, &mut out);
+        assert_eq!(out, v.to_le_bytes());
+        test_write_ne_bytes(v, &mut out);
+        assert_eq!(out, v.to_ne_bytes());
+
+        let mut out = [0; 4];
+        test_write_be_bytes('a', &mut out);
+        assert_eq!(out, [0, 0, 0, b'a']);
+    }
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(parse_array::<u8, 3>("1,2,3", ','), Ok([1, 2, 3]));
+        assert_eq!(parse_array::<f32, 2>("1.5 -2", ' '), Ok([1.5, -2.0]));
+        assert_eq!(
+            parse_array::<u8, 3>("1,2", ','),
+            Err(ParseArrayError::WrongLength {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            parse_array::<u8, 1>("1,2", ','),
+            Err(ParseArrayError::WrongLength {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            parse_array::<u8, 3>("1,256,3", ','),
+            Err(ParseArrayError::Parse { index: 1, .. })
+        ));
+        assert_eq!(parse_array::<u8, 0>("", ','), Ok([]));
+        assert_eq!(
+            parse_array::<u8, 1>("", ','),
+            Err(ParseArrayError::WrongLength {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_shift_by() {
+        fn test_checked_shl_by<T: Integer>(a: T, b: T) -> Option<T> {
+            a.checked_shl_by(b)
+        }
+
+        fn test_checked_shr_by<T: Integer>(a: T, b: T) -> Option<T> {
+            a.checked_shr_by(b)
+        }
+
+        assert_eq!(test_checked_shl_by(1u64, 63), Some(1 << 63));
+        assert_eq!(test_checked_shl_by(1u64, 64), None);
+        assert_eq!(test_checked_shl_by(1u64, u64::MAX), None);
+        assert_eq!(test_checked_shr_by(256u64, 8), Some(1));
+        assert_eq!(test_checked_shr_by(-8i8, 1), Some(-4));
+        assert_eq!(test_checked_shr_by(-8i8, -1), None);
+        assert_eq!(test_checked_shl_by(1u8, 8), None);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        fn test_magnitude<T: Integer>(a: T) -> T::Unsigned {
+            a.magnitude()
+        }
+
+        assert_eq!(test_magnitude(i8::MIN), 128u8);
+        assert_eq!(test_magnitude(-5i32), 5u32);
+        assert_eq!(test_magnitude(5i32), 5u32);
+        assert_eq!(test_magnitude(200u8), 200);
+        assert_eq!(test_magnitude(i128::MIN), 1 << 127);
+    }
+
+    #[test]
+    fn test_finite_bounds() {
+        fn test_finite_bounds<T: Number>() -> (T, T) {
+            (T::min_finite(), T::max_finite())
+        }
+
+        let (min, max) = test_finite_bounds::<f32>();
+        assert!(min.is_finite() && max.is_finite());
+        assert_eq!((min, max), (f32::MIN, f32::MAX));
+        assert_eq!(test_finite_bounds::<f64>(), (f64::MIN, f64::MAX));
+        assert_eq!(test_finite_bounds::<i8>(), (-128, 127));
+        assert_eq!(test_finite_bounds::<u64>(), (0, u64::MAX));
+    }
+
+    #[test]
+    fn test_saturating_wrapping_abs() {
+        fn test_saturating_abs<T: Number>(a: T) -> T {
+            a.saturating_abs()
+        }
+
+        fn test_wrapping_abs<T: Number>(a: T) -> T {
+            a.wrapping_abs()
+        }
+
+        assert_eq!(test_saturating_abs(i8::MIN), i8::MAX);
+        assert_eq!(test_saturating_abs(-3i32), 3);
+        assert_eq!(test_saturating_abs(200u8), 200);
+        assert_eq!(test_saturating_abs(-1.5f32), 1.5);
+        assert_eq!(test_wrapping_abs(i8::MIN), i8::MIN);
+        assert_eq!(test_wrapping_abs(-3i32), 3);
+        assert_eq!(test_wrapping_abs(200u8), 200);
+        assert_eq!(test_wrapping_abs(-1.5f64), 1.5);
+    }
+
+    #[test]
+    fn test_count_digits() {
+        fn test_count_digits<T: Integer>(a: T) -> u32 {
+            a.count_digits()
+        }
+
+        assert_eq!(test_count_digits(0u8), 1);
+        assert_eq!(test_count_digits(9u8), 1);
+        assert_eq!(test_count_digits(999i32), 3);
+        assert_eq!(test_count_digits(1000i32), 4);
+        assert_eq!(test_count_digits(-1000i32), 4);
+        assert_eq!(test_count_digits(i8::MIN), 3);
+        assert_eq!(test_count_digits(u128::MAX), 39);
+    }
+
+    #[test]
+    fn test_low_bits() {
+        fn test_low_bits<T: Unsigned>(a: T, n: u32) -> T {
+            a.low_bits(n)
+        }
+
+        assert_eq!(test_low_bits(0xffu8, 4), 0x0f);
+        assert_eq!(test_low_bits(0xffu8, 8), 0xff);
+        assert_eq!(test_low_bits(0xffu8, 100), 0xff);
+        assert_eq!(test_low_bits(0xffu8, 0), 0);
+        assert_eq!(test_low_bits(u128::MAX, 127), u128::MAX >> 1);
+        assert_eq!(test_low_bits(0b1010_1100u32, 3), 0b100);
+    }
+
+    #[test]
+    fn test_sign_extend() {
+        fn test_sign_extend<T: Signed>(a: T, from_bits: u32) -> T {
+            a.sign_extend(from_bits)
+        }
+
+        assert_eq!(test_sign_extend(0b1111i8, 4), -1);
+        assert_eq!(test_sign_extend(0b0111i8, 4), 7);
+        assert_eq!(test_sign_extend(0b1_0111i8, 4), 7);
+        assert_eq!(test_sign_extend(0b1000i32, 4), -8);
+        assert_eq!(test_sign_extend(1i64, 1), -1);
+        assert_eq!(test_sign_extend(-1i16, 0), 0);
+        assert_eq!(test_sign_extend(0x7fi8, 8), 0x7f);
+        assert_eq!(test_sign_extend(-5i8, 100), -5);
+    }
+
+    #[test]
+    fn test_transparent_number() {
+        fn test_transparent_number<T: TransparentNumber>(a: T) -> T {
+            a.to_underlying()
+        }
+
+        assert_eq!(test_transparent_number(42u32), 42);
+        assert_eq!(test_transparent_number(-1i8), -1);
+        assert_eq!(test_transparent_number(1.5f64), 1.5);
+    }
+
+    #[test]
+    fn test_rotate() {
+        fn test_rotate<T: Integer>(a: T, n: i32) -> T {
+            a.rotate(n)
+        }
+
+        assert_eq!(test_rotate(0x01u8, -1), 0x80);
+        assert_eq!(test_rotate(0x80u8, 1), 0x01);
+        assert_eq!(test_rotate(0x12u8, 0), 0x12);
+        assert_eq!(test_rotate(0x12u8, 8), 0x12);
+        assert_eq!(test_rotate(0x12u8, -12), 0x21);
+        assert_eq!(test_rotate(1i32, i32::MIN), 1);
+        assert_eq!(test_rotate(1u128, -1), 1 << 127);
+    }
+
+    #[test]
+    fn test_bits() {
+        fn test_bits<T: Number>(a: T) -> T::Bits {
+            let bits = a.to_bits();
+            assert_eq!(T::from_bits(bits), a);
+            bits
+        }
+
+        assert_eq!(test_bits(-1i32), u32::MAX);
+        assert_eq!(test_bits(i32::MIN), 1 << 31);
+        assert_eq!(test_bits(123u64), 123);
+        assert_eq!(test_bits(1.0f32), 0x3f80_0000);
+        assert_eq!(test_bits(-0.0f64), 1 << 63);
+    }
+
+    #[test]
+    fn test_cardinality() {
+        fn test_cardinality<T: NumberLike>() -> u128 {
+            T::CARDINALITY
+        }
+
+        assert_eq!(test_cardinality::<bool>(), 2);
+        assert_eq!(test_cardinality::<u8>(), 256);
+        assert_eq!(test_cardinality::<i16>(), 1 << 16);
+        assert_eq!(test_cardinality::<f32>(), 1 << 32);
+        assert_eq!(test_cardinality::<u64>(), 1 << 64);
+        assert_eq!(test_cardinality::<i128>(), u128::MAX);
+        // All code points except the surrogates `0xd800..=0xdfff`.
+        assert_eq!(test_cardinality::<char>(), 0x110000 - 0x800);
+    }
+
+    #[test]
+    fn test_lerp() {
+        fn test_lerp<T: Float>(a: T, b: T, t: T) -> T {
+            a.lerp(b, t)
+        }
+
+        fn test_lerp_clamped<T: Float>(a: T, b: T, t: T) -> T {
+            a.lerp_clamped(b, t)
+        }
+
+        assert_eq!(test_lerp(0.0f64, 10.0, 0.5), 5.0);
+        assert_eq!(test_lerp(0.1f32, 0.7, 0.0), 0.1);
+        assert_eq!(test_lerp(0.1f32, 0.7, 1.0), 0.7);
+        assert_eq!(test_lerp(1.0f64, 2.0, 2.0), 3.0);
+        assert_eq!(test_lerp_clamped(1.0f64, 2.0, 2.0), 2.0);
+        assert_eq!(test_lerp_clamped(1.0f64, 2.0, -1.0), 1.0);
+
+        let mut prev = f32::NEG_INFINITY;
+        for i in 0..=100 {
+            let v = test_lerp(-3.3f32, 7.1, i as f32 / 100.0);
+            assert!(v >= prev);
+            prev = v;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_trig_deg() {
+        fn test_trig_deg<T: Float>(a: T) -> [T; 3] {
+            [a.sin_deg(), a.cos_deg(), a.tan_deg()]
+        }
+
+        fn assert_approx_eq<T: Float>(a: T, b: T) {
+            let mut diff = a;
+            diff -= b;
+            let mut tolerance = T::EPSILON;
+            tolerance *= T::TWO;
+            assert!(diff.abs() <= tolerance, "{a} != {b}");
+        }
+
+        let [sin, cos, _] = test_trig_deg(90.0f64);
+        assert_approx_eq(sin, 1.0);
+        assert_approx_eq(cos, 0.0);
+
+        let [sin, cos, tan] = test_trig_deg(180.0f64);
+        assert_approx_eq(sin, 0.0);
+        assert_approx_eq(cos, -1.0);
+        assert_approx_eq(tan, 0.0);
+
+        let [sin, cos, tan] = test_trig_deg(45.0f32);
+        assert_approx_eq(sin, core::f32::consts::FRAC_1_SQRT_2);
+        assert_approx_eq(cos, core::f32::consts::FRAC_1_SQRT_2);
+        assert_approx_eq(tan, 1.0);
+    }
+
+    #[test]
+    fn test_checked_recip() {
+        fn test_checked_recip<T: Number>(a: T) -> Option<T> {
+            a.checked_recip()
+        }
+
+        assert_eq!(test_checked_recip(2.0f64), Some(0.5));
+        assert_eq!(test_checked_recip(0.0f64), Some(f64::INFINITY));
+        assert_eq!(test_checked_recip(-0.0f32), Some(f32::NEG_INFINITY));
+        assert_eq!(test_checked_recip(0i32), None);
+        assert_eq!(test_checked_recip(1i32), Some(1));
+        assert_eq!(test_checked_recip(-1i8), Some(-1));
+        assert_eq!(test_checked_recip(2u8), Some(0));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        assert_eq!(sum([1u32, 2, 3]), 6);
+        assert_eq!(sum([0.5f64, 0.25]), 0.75);
+        assert_eq!(sum::<i8, _>([]), 0);
+        assert_eq!(product([2.0f32, 3.0]), 6.0);
+        assert_eq!(product([-2i64, 3, 4]), -24);
+        assert_eq!(product::<u8, _>([]), 1);
+    }
+
+    #[test]
+    fn test_cast_with() {
+        fn test_cast_with<T: Number, U: Number>(v: T, policy: CastPolicy) -> Option<U> {
+            v.cast_with(policy)
+        }
+
+        assert_eq!(test_cast_with::<_, u8>(300i32, CastPolicy::Wrap), Some(44));
+        assert_eq!(
+            test_cast_with::<_, u8>(300i32, CastPolicy::Saturate),
+            Some(255)
+        );
+        assert_eq!(test_cast_with::<_, u8>(300i32, CastPolicy::Checked), None);
+        assert_eq!(
+            test_cast_with::<_, u8>(200i32, CastPolicy::Checked),
+            Some(200)
+        );
+        assert_eq!(test_cast_with::<_, i8>(-1.5f32, CastPolicy::Wrap), Some(-1));
+        assert_eq!(test_cast_with::<_, u8>(-1.5f32, CastPolicy::Wrap), Some(0));
+        assert_eq!(test_cast_with::<_, u8>(f64::NAN, CastPolicy::Wrap), Some(0));
+        assert_eq!(
+            test_cast_with::<_, f32>(u8::MAX, CastPolicy::Wrap),
+            Some(255.0)
+        );
+    }
+
+    #[cfg(feature = "hex")]
+    #[test]
+    fn test_hex() {
+        fn test_hex<T: NumberLike>(v: T) -> T::HexArray {
+            v.to_hex()
+        }
+
+        assert_eq!(test_hex(0xabu8), *b"ab");
+        assert_eq!(test_hex(0x0123_abcdu32), *b"0123abcd");
+        assert_eq!(test_hex(-1i16), *b"ffff");
+        assert_eq!(test_hex(true), *b"01");
+        assert_eq!(test_hex('A'), *b"00000041");
+        assert_eq!(0x0123_abcdu32.to_hex_le(), *b"cdab2301");
+
+        assert_eq!(u8::from_hex(*b"ab"), Some(0xab));
+        assert_eq!(u8::from_hex(*b"AB"), Some(0xab));
+        assert_eq!(u8::from_hex(*b"ag"), None);
+        assert_eq!(u32::from_hex_le(*b"cdab2301"), Some(0x0123_abcd));
+        assert_eq!(bool::from_hex(*b"02"), None);
+        assert_eq!(char::from_hex(*b"0000d800"), None);
+
+        for v in [0.0f64, -1.5, f64::MAX, f64::NEG_INFINITY] {
+            assert_eq!(f64::from_hex(v.to_hex()), Some(v));
+            assert_eq!(f64::from_hex_le(v.to_hex_le()), Some(v));
+        }
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum([200u8, 100]), None);
+        assert_eq!(checked_sum([1u8, 2, 3]), Some(6));
+        assert_eq!(checked_sum([i8::MIN, 1, -1]), Some(i8::MIN));
+        assert_eq!(checked_sum([i8::MIN, -1, 1]), None);
+        assert_eq!(checked_sum::<u32, _>([]), Some(0));
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        fn test_try_from_be_slice<T: NumberLike>(bytes: &[u8]) -> Option<T> {
+            T::try_from_be_slice(bytes)
+        }
+
+        let bytes = [0x01, 0x02, 0x03, 0x04];
+        assert_eq!(test_try_from_be_slice::<u32>(&bytes), Some(0x0102_0304));
+        assert_eq!(test_try_from_be_slice::<u32>(&bytes[..3]), None);
+        assert_eq!(test_try_from_be_slice::<u16>(&bytes), None);
+        assert_eq!(u32::try_from_le_slice(&bytes), Some(0x0403_0201));
+        assert_eq!(
+            u32::try_from_ne_slice(&bytes),
+            Some(u32::from_ne_bytes(bytes))
+        );
+        assert_eq!(test_try_from_be_slice::<bool>(&[1]), Some(true));
+        assert_eq!(test_try_from_be_slice::<bool>(&[2]), None);
+        assert_eq!(test_try_from_be_slice::<char>(&[0, 0, 0xd8, 0]), None);
+    }
+
+    #[test]
+    fn test_num_min_max() {
+        fn num_min<T: Number>(a: T, b: T) -> T {
+            a.num_min(b)
+        }
+
+        fn num_max<T: Number>(a: T, b: T) -> T {
+            a.num_max(b)
+        }
+
+        assert_eq!(num_min(3u8, 5), 3);
+        assert_eq!(num_max(3u8, 5), 5);
+        assert_eq!(num_min(-3i32, 5), -3);
+        assert_eq!(num_max(-3i32, -5), -3);
+        assert_eq!(num_min(1.5f32, -2.0), -2.0);
+        assert_eq!(num_max(1.5f64, -2.0), 1.5);
+        assert_eq!(num_min(f64::NAN, 1.0), 1.0);
+        assert_eq!(num_max(1.0, f32::NAN), 1.0);
+        assert!(num_min(f64::NAN, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_num_clamp() {
+        fn num_clamp<T: Number>(v: T, min: T, max: T) -> T {
+            v.num_clamp(min, max)
+        }
+
+        assert_eq!(num_clamp(7u8, 2, 5), 5);
+        assert_eq!(num_clamp(-7i16, -2, 5), -2);
+        assert_eq!(num_clamp(3i64, -2, 5), 3);
+        assert_eq!(num_clamp(1.5f32, 0.0, 1.0), 1.0);
+        assert!(num_clamp(f64::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_num_clamp_invalid_bounds() {
+        fn num_clamp<T: Number>(v: T, min: T, max: T) -> T {
+            v.num_clamp(min, max)
+        }
+
+        num_clamp(3u32, 5, 2);
+    }
+
+    #[test]
+    fn test_is_signed() {
+        fn is_signed<T: Integer>() -> bool {
+            T::IS_SIGNED
+        }
+
+        const { assert!(<i32 as Integer>::IS_SIGNED) };
+        const { assert!(!<u32 as Integer>::IS_SIGNED) };
+        assert!(is_signed::<i8>());
+        assert!(is_signed::<isize>());
+        assert!(!is_signed::<u128>());
+        assert!(!is_signed::<usize>());
+    }
+
+    #[test]
+    fn test_is_float_is_integer() {
+        fn category<T: Number>() -> (bool, bool) {
+            (T::IS_FLOAT, T::IS_INTEGER)
+        }
+
+        const { assert!(<f32 as Number>::IS_FLOAT) };
+        const { assert!(<u8 as Number>::IS_INTEGER) };
+        assert_eq!(category::<f64>(), (true, false));
+        assert_eq!(category::<i64>(), (false, true));
+        assert_eq!(category::<usize>(), (false, true));
+    }
+
+    #[test]
+    fn test_to_u128_key() {
+        fn assert_key_order<T: NumberLike>(values: &[T]) {
+            for &a in values {
+                for &b in values {
+                    match a.partial_cmp(&b) {
+                        Some(ordering) if ordering.is_ne() => {
+                            assert_eq!(a.to_u128_key().cmp(&b.to_u128_key()), ordering);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let all_i8: [i8; 256] = core::array::from_fn(|i| i as i8);
+        assert_key_order(&all_i8);
+        assert_eq!(i8::MIN.to_u128_key(), 0);
+        assert_eq!(0i8.to_u128_key(), 128);
+        assert_eq!(i8::MAX.to_u128_key(), 255);
+        assert_eq!(u64::MAX.to_u128_key(), u64::MAX as u128);
+        assert_eq!(i128::MIN.to_u128_key(), 0);
+        assert_eq!(i128::MAX.to_u128_key(), u128::MAX);
+
+        assert_key_order(&[
+            f32::NEG_INFINITY,
+            f32::MIN,
+            -1.5,
+            -f32::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            1e-45,
+            f32::MIN_POSITIVE,
+            1.0,
+            1.5,
+            f32::MAX,
+            f32::INFINITY,
+        ]);
+        assert!((-0.0f32).to_u128_key() < 0.0f32.to_u128_key());
+        assert!(f64::INFINITY.to_u128_key() < f64::NAN.to_u128_key());
+
+        assert_key_order(&[false, true]);
+        assert_key_order(&['\0', 'A', 'a', '\u{10ffff}']);
+    }
+
+    #[test]
+    fn test_hash_bits() {
+        extern crate std;
+        use std::hash::DefaultHasher;
+
+        fn hash<T: Number>(v: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash_bits(&mut hasher);
+            hasher.finish()
+        }
+
+        fn std_hash<T: Hash>(v: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(0.0f64), hash(-0.0f64));
+        assert_eq!(hash(0.0f32), hash(-0.0f32));
+        assert_eq!(hash(f64::NAN), hash(f64::NAN));
+        assert_eq!(hash(f64::NAN), hash(-f64::NAN));
+        assert_eq!(hash(f32::NAN), hash(f32::from_bits(0x7fc0_0001)));
+        assert_ne!(hash(1.0f64), hash(-1.0f64));
+        assert_ne!(hash(1.0f32), hash(2.0f32));
+
+        assert_eq!(hash(-5i32), std_hash(-5i32));
+        assert_eq!(hash(42u8), std_hash(42u8));
+        assert_ne!(hash(1u64), hash(2u64));
+    }
+
+    #[test]
+    fn test_num_cmp() {
+        fn num_cmp<T: Number>(a: T, b: T) -> Ordering {
+            a.num_cmp(b)
+        }
+
+        fn sort<T: Number>(values: &mut [T]) {
+            values.sort_by(|&a, &b| a.num_cmp(b));
+        }
+
+        assert_eq!(num_cmp(1u8, 2), Ordering::Less);
+        assert_eq!(num_cmp(-1i32, -1), Ordering::Equal);
+        assert_eq!(num_cmp(i64::MAX, i64::MIN), Ordering::Greater);
+        assert_eq!(num_cmp(-0.0f32, 0.0), Ordering::Less);
+        assert_eq!(num_cmp(f64::NAN, f64::INFINITY), Ordering::Greater);
+        assert_eq!(num_cmp(f64::NAN, f64::NAN), Ordering::Equal);
+
+        let mut values = [3.0, f64::NAN, 1.0];
+        sort(&mut values);
+        assert_eq!(values[..2], [1.0, 3.0]);
+        assert!(values[2].is_nan());
+
+        let mut values = [3, -7, 0, 2];
+        sort(&mut values);
+        assert_eq!(values, [-7, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_by_magnitude() {
+        fn min_by_magnitude<T: Number>(a: T, b: T) -> T {
+            a.min_by_magnitude(b)
+        }
+
+        fn max_by_magnitude<T: Number>(a: T, b: T) -> T {
+            a.max_by_magnitude(b)
+        }
+
+        assert_eq!(max_by_magnitude(-5i32, 3), -5);
+        assert_eq!(min_by_magnitude(-5i32, 3), 3);
+        assert_eq!(max_by_magnitude(i8::MIN, i8::MAX), i8::MIN);
+        assert_eq!(min_by_magnitude(i8::MIN, i8::MAX), i8::MAX);
+        assert_eq!(max_by_magnitude(-4i64, 4), -4);
+        assert_eq!(max_by_magnitude(4i64, -4), 4);
+        assert_eq!(max_by_magnitude(2u8, 7), 7);
+        assert_eq!(min_by_magnitude(2u8, 7), 2);
+
+        assert_eq!(min_by_magnitude(2.0f64, -1.0), -1.0);
+        assert_eq!(max_by_magnitude(2.0f64, -3.0), -3.0);
+        assert_eq!(min_by_magnitude(f32::NAN, -1.0), -1.0);
+        assert_eq!(max_by_magnitude(-1.0, f32::NAN), -1.0);
+        assert!(max_by_magnitude(f64::NAN, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_hamming() {
+        fn hamming_distance<T: Integer>(a: T, b: T) -> u32 {
+            a.hamming_distance(b)
+        }
+
+        fn hamming_weight<T: Integer>(a: T) -> u32 {
+            a.hamming_weight()
+        }
+
+        assert_eq!(hamming_distance(0b1010u8, 0b0110), 2);
+        assert_eq!(hamming_distance(7u32, 7), 0);
+        assert_eq!(hamming_distance(0i16, -1), 16);
+        assert_eq!(hamming_distance(u128::MAX, 0), 128);
+        assert_eq!(hamming_weight(0xffu8), 8);
+        assert_eq!(hamming_weight(0u64), 0);
+        assert_eq!(hamming_weight(-1i32), 32);
+        assert_eq!(hamming_weight(i8::MIN), 1);
+    }
+
+    #[test]
+    fn test_checked_add_other() {
+        fn checked_add_other<T: MixedSignArithmetic>(a: T, b: T::OtherSign) -> Option<T> {
+            a.checked_add_other(b)
+        }
+
+        assert_eq!(checked_add_other(5u32, -3i32), Some(2));
+        assert_eq!(checked_add_other(5i32, 3u32), Some(8));
+        assert_eq!(checked_add_other(2u8, -3i8), None);
+        assert_eq!(checked_add_other(250u8, 5i8), Some(255));
+        assert_eq!(checked_add_other(-128i8, 255u8), Some(127));
+        assert_eq!(checked_add_other(1i8, 127u8), None);
+    }
+
+    #[test]
+    fn test_from_hex_float() {
+        fn parse<T: Float>(s: &str) -> Option<T> {
+            T::from_hex_float(s)
+        }
+
+        assert_eq!(parse::<f64>("0x1.8p1"), Some(3.0));
+        assert_eq!(parse::<f64>("0x1p-1"), Some(0.5));
+        assert_eq!(parse::<f32>("0X1.8P+1"), Some(3.0));
+        assert_eq!(
+            parse::<f64>("-0x1.921fb54442d18p+1"),
+            Some(-core::f64::consts::PI)
+        );
+        assert_eq!(parse::<f64>("+0xA.8p-2"), Some(2.625));
+        assert_eq!(parse::<f64>("0x.8p0"), Some(0.5));
+        assert_eq!(parse::<f64>("0x3.p0"), Some(3.0));
+        assert_eq!(parse::<f64>("0x0000000000000000000001p4"), Some(16.0));
+
+        let zero = parse::<f64>("-0x0p0").unwrap();
+        assert_eq!(zero, 0.0);
+        assert!(zero.is_sign_negative());
+        assert_eq!(parse::<f32>("0x0.000p99999999999999999999"), Some(0.0));
+
+        assert_eq!(parse::<f64>("0x1.fffffffffffffp1023"), Some(f64::MAX));
+        assert_eq!(parse::<f64>("0x1.fffffffffffff8p1023"), Some(f64::INFINITY));
+        assert_eq!(parse::<f64>("0x1p1024"), Some(f64::INFINITY));
+        assert_eq!(parse::<f32>("0x1.fffffep127"), Some(f32::MAX));
+        assert_eq!(parse::<f32>("0x1.ffffffp127"), Some(f32::INFINITY));
+        assert_eq!(parse::<f32>("-0x1p99999999999999"), Some(f32::NEG_INFINITY));
+
+        assert_eq!(parse::<f64>("0x1p-1022"), Some(f64::MIN_POSITIVE));
+        assert_eq!(parse::<f64>("0x1p-1074"), Some(f64::MIN_POSITIVE_SUBNORMAL));
+        assert_eq!(parse::<f64>("0x1p-1075"), Some(0.0));
+        assert_eq!(
+            parse::<f64>("0x1.0000001p-1075"),
+            Some(f64::MIN_POSITIVE_SUBNORMAL)
+        );
+        assert_eq!(
+            parse::<f64>("0x1.8p-1074"),
+            Some(2.0 * f64::MIN_POSITIVE_SUBNORMAL)
+        );
+        assert_eq!(parse::<f32>("0x1p-149"), Some(f32::MIN_POSITIVE_SUBNORMAL));
+        assert_eq!(
+            parse::<f32>("0x1.fffffcp-127"),
+            Some(f32::MIN_POSITIVE - f32::MIN_POSITIVE_SUBNORMAL)
+        );
+        assert_eq!(parse::<f32>("0x1.fffffep-127"), Some(f32::MIN_POSITIVE));
+        assert_eq!(parse::<f32>("0x1p-99999999"), Some(0.0));
+
+        // Ties round to even, and digits beyond 64 bits still break ties.
+        assert_eq!(parse::<f32>("0x1.000001p0"), Some(1.0));
+        assert_eq!(parse::<f32>("0x1.000003p0"), Some(1.0 + 2.0 * f32::EPSILON));
+        assert_eq!(
+            parse::<f32>("0x1.00000100000000000000001p0"),
+            Some(1.0 + f32::EPSILON)
+        );
+
+        assert_eq!(parse::<f64>("inf"), Some(f64::INFINITY));
+        assert_eq!(parse::<f32>("-Infinity"), Some(f32::NEG_INFINITY));
+        assert!(parse::<f64>("NaN").unwrap().is_nan());
+
+        for s in [
+            "",
+            "0x",
+            "0xp1",
+            "0x.p1",
+            "1.5",
+            "0x1.8",
+            "0x1.8p",
+            "0x1.8p+",
+            "0xg1p0",
+            "0x1p+-1",
+            "0x1.2.3p0",
+            "--0x1p0",
+            "0x1p1.5",
+            " 0x1p0",
+            "0x1p0 ",
+            "nan1",
+        ] {
+            assert_eq!(parse::<f64>(s), None, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_to_hex_float() {
+        extern crate std;
+        use std::{format, string::String};
+
+        fn to_hex_float<T: Float>(v: T) -> String {
+            format!("{}", v.to_hex_float())
+        }
+
+        fn roundtrip<T: Float>(v: T) {
+            let s = to_hex_float(v);
+            let parsed = T::from_hex_float(&s).unwrap();
+            if v.is_nan() {
+                assert!(parsed.is_nan(), "{s}");
+            } else {
+                assert_eq!(parsed.to_bits(), v.to_bits(), "{s}");
+            }
+        }
+
+        assert_eq!(to_hex_float(3.0f64), "0x1.8p+1");
+        assert_eq!(to_hex_float(0.5f64), "0x1p-1");
+        assert_eq!(to_hex_float(1.0f32), "0x1p+0");
+        assert_eq!(to_hex_float(-0.1f32), "-0x1.99999ap-4");
+        assert_eq!(to_hex_float(0.1f64), "0x1.999999999999ap-4");
+        assert_eq!(to_hex_float(f64::MAX), "0x1.fffffffffffffp+1023");
+        assert_eq!(to_hex_float(f32::MAX), "0x1.fffffep+127");
+        assert_eq!(to_hex_float(0.0f64), "0x0p+0");
+        assert_eq!(to_hex_float(-0.0f32), "-0x0p+0");
+        assert_eq!(to_hex_float(f64::MIN_POSITIVE), "0x1p-1022");
+        assert_eq!(
+            to_hex_float(f64::MIN_POSITIVE_SUBNORMAL),
+            "0x0.0000000000001p-1022"
+        );
+        assert_eq!(to_hex_float(f32::MIN_POSITIVE_SUBNORMAL), "0x0.000002p-126");
+        assert_eq!(to_hex_float(f32::INFINITY), "inf");
+        assert_eq!(to_hex_float(f64::NEG_INFINITY), "-inf");
+        assert_eq!(to_hex_float(-f64::NAN), "nan");
+
+        // Miri is too slow to check this many values.
+        let count = if cfg!(miri) { 100 } else { 10_000 };
+        for i in 0..count {
+            let bits = i.wrapping_mul(0x9e37_79b9_7f4a_7c15u64);
+            roundtrip(f64::from_bits(bits));
+            roundtrip(f32::from_bits((bits >> 32) as u32));
+            roundtrip(f64::from_bits(i));
+            roundtrip(f32::from_bits(i as u32));
+        }
+        for v in [
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE_SUBNORMAL,
+            f64::NAN,
+        ] {
+            roundtrip(v);
+        }
+    }
+
+    #[test]
+    fn test_next_after() {
+        fn next_after<T: Float>(v: T, toward: T) -> T {
+            v.next_after(toward)
+        }
+
+        assert_eq!(next_after(0.0f64, 1.0), f64::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(next_after(0.0f32, -1.0), -f32::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(next_after(f64::MIN_POSITIVE_SUBNORMAL, -1.0), 0.0);
+        assert_eq!(next_after(1.0f64, 1.0), 1.0);
+        assert_eq!(next_after(1.0f64, 2.0), 1.0 + f64::EPSILON);
+        assert_eq!(next_after(1.0f32, 0.0), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(next_after(f32::MAX, f32::INFINITY), f32::INFINITY);
+        assert_eq!(next_after(f64::INFINITY, 0.0), f64::MAX);
+
+        let zero = next_after(-0.0f64, 0.0);
+        assert_eq!(zero, 0.0);
+        assert!(zero.is_sign_positive());
+        assert!(next_after(0.0f64, -0.0).is_sign_negative());
+
+        assert!(next_after(f32::NAN, 1.0).is_nan());
+        assert!(next_after(1.0, f64::NAN).is_nan());
+
+        assert_eq!(Float::next_up(1.0f32), 1.0 + f32::EPSILON);
+        assert_eq!(Float::next_down(0.0f64), -f64::MIN_POSITIVE_SUBNORMAL);
+    }
+
+    #[test]
+    fn test_saturating_from_str() {
+        fn parse<T: Integer>(s: &str) -> Result<T, ParseIntError> {
+            T::saturating_from_str(s)
+        }
+
+        assert_eq!(parse::<u8>("999999999999"), Ok(u8::MAX));
+        assert_eq!(parse::<u8>("42"), Ok(42));
+        assert_eq!(parse::<i32>("-99999999999999999999999999999"), Ok(i32::MIN));
+        assert_eq!(parse::<i32>("+99999999999999999999999999999"), Ok(i32::MAX));
+        assert_eq!(parse::<i64>("-17"), Ok(-17));
+        assert_eq!(
+            parse::<u128>("340282366920938463463374607431768211456"),
+            Ok(u128::MAX)
+        );
+
+        assert_eq!(
+            *parse::<u8>("abc").unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(*parse::<u8>("").unwrap_err().kind(), IntErrorKind::Empty);
+        assert_eq!(parse::<u8>("-1"), Ok(0));
+        assert_eq!(parse::<u64>("-0"), Ok(0));
+        assert_eq!(parse::<u32>("-99999999999999999999"), Ok(0));
+        assert_eq!(
+            *parse::<u8>("-").unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            *parse::<u8>("-1x").unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            *parse::<i8>("12x").unwrap_err().kind(),
+            IntErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn test_real_number() {
+        fn from_bytes_total<T: RealNumber>(bytes: T::ByteArray) -> T {
+            T::from_bytes_total(bytes)
+        }
+
+        assert_eq!(from_bytes_total::<u32>(7u32.to_ne_bytes()), 7);
+        assert_eq!(from_bytes_total::<f64>(1.5f64.to_ne_bytes()), 1.5);
+        assert_eq!(from_bytes_total::<i8>([0xff]), -1);
+        assert!(from_bytes_total::<f32>([0xff; 4]).is_nan());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_next_power_of() {
+        fn next_power_of<T: Float>(v: T, base: T) -> T {
+            v.next_power_of(base)
+        }
+
+        assert_eq!(next_power_of(50.0f64, 10.0), 100.0);
+        assert_eq!(next_power_of(100.0f64, 10.0), 100.0);
+        assert_eq!(next_power_of(100.5f64, 10.0), 1000.0);
+        assert_eq!(next_power_of(1.0f64, 10.0), 1.0);
+        assert_eq!(next_power_of(0.05f64, 10.0), 0.1);
+        assert_eq!(next_power_of(1000.0f32, 10.0), 1000.0);
+        assert_eq!(next_power_of(5.0f32, 2.0), 8.0);
+        assert_eq!(next_power_of(0.3f64, 3.0), 1.0 / 3.0);
+        for exp in 0..=20 {
+            let v = 10f64.powi(exp);
+            assert_eq!(next_power_of(v, 10.0), v, "{v}");
+            assert_eq!(next_power_of(v * 1.5, 10.0), v * 10.0, "{v}");
+        }
+
+        assert_eq!(next_power_of(f64::INFINITY, 10.0), f64::INFINITY);
+        assert_eq!(next_power_of(f64::MAX, 10.0), f64::INFINITY);
+        assert_eq!(next_power_of(0.5f64, f64::INFINITY), 1.0);
+        assert_eq!(next_power_of(2.0f64, f64::INFINITY), f64::INFINITY);
+
+        assert!(next_power_of(0.0f64, 10.0).is_nan());
+        assert!(next_power_of(-5.0f64, 10.0).is_nan());
+        assert!(next_power_of(5.0f64, 1.0).is_nan());
+        assert!(next_power_of(5.0f64, 0.5).is_nan());
+        assert!(next_power_of(f32::NAN, 2.0).is_nan());
+        assert!(next_power_of(2.0, f32::NAN).is_nan());
+    }
 }
@@ -1,10 +1,348 @@
-use core::fmt::Debug;
+use core::{fmt::Debug, ptr};
 
 use crate::primitive::Primitive;
 
-pub trait Pointer: Primitive + Copy + Debug + Sized {}
+pub trait Pointer: Primitive + Copy + Debug + Sized {
+    type Pointee: ?Sized;
+
+    /// See `pointer::addr`.
+    fn addr(self) -> usize;
+
+    /// See `pointer::expose_provenance`.
+    fn expose_provenance(self) -> usize;
+
+    /// See [`ptr::with_exposed_provenance`] and
+    /// [`ptr::with_exposed_provenance_mut`].
+    fn from_exposed_provenance(addr: usize) -> Self
+    where
+        Self::Pointee: Sized;
+
+    /// See `pointer::align_offset`.
+    ///
+    /// Returns [`usize::MAX`] if it is not possible to align the pointer.
+    fn align_offset(self, align: usize) -> usize
+    where
+        Self::Pointee: Sized;
+
+    /// See `pointer::read_volatile`.
+    ///
+    /// # Safety
+    ///
+    /// See [`ptr::read_volatile`].
+    unsafe fn read_volatile(self) -> Self::Pointee
+    where
+        Self::Pointee: Sized;
+
+    /// See `pointer::to_raw_parts`.
+    #[cfg(feature = "ptr_metadata")]
+    fn to_raw_parts(self) -> (*const (), <Self::Pointee as ptr::Pointee>::Metadata);
+
+    /// See [`ptr::from_raw_parts`] and [`ptr::from_raw_parts_mut`].
+    #[cfg(feature = "ptr_metadata")]
+    fn from_raw_parts(data: *const (), metadata: <Self::Pointee as ptr::Pointee>::Metadata)
+        -> Self;
+
+    /// See `pointer::guaranteed_eq`.
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_eq(self, other: Self) -> Option<bool>
+    where
+        Self::Pointee: Sized;
+
+    /// See `pointer::guaranteed_ne`.
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_ne(self, other: Self) -> Option<bool>
+    where
+        Self::Pointee: Sized;
+}
+
+/// A [`Pointer`] that allows mutating its pointee, i.e. `*mut T`.
+pub trait PointerMut: Pointer {
+    /// See `pointer::write_volatile`.
+    ///
+    /// # Safety
+    ///
+    /// See [`ptr::write_volatile`].
+    unsafe fn write_volatile(self, val: Self::Pointee)
+    where
+        Self::Pointee: Sized;
+}
 
 impl<T: ?Sized> Primitive for *const T {}
-impl<T: ?Sized> Pointer for *const T {}
+impl<T: ?Sized> Pointer for *const T {
+    type Pointee = T;
+
+    fn addr(self) -> usize {
+        self.addr()
+    }
+
+    fn expose_provenance(self) -> usize {
+        self.expose_provenance()
+    }
+
+    fn from_exposed_provenance(addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        ptr::with_exposed_provenance(addr)
+    }
+
+    fn align_offset(self, align: usize) -> usize
+    where
+        T: Sized,
+    {
+        self.align_offset(align)
+    }
+
+    unsafe fn read_volatile(self) -> T
+    where
+        T: Sized,
+    {
+        self.read_volatile()
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    fn to_raw_parts(self) -> (*const (), <T as ptr::Pointee>::Metadata) {
+        self.to_raw_parts()
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    fn from_raw_parts(data: *const (), metadata: <T as ptr::Pointee>::Metadata) -> Self {
+        ptr::from_raw_parts(data, metadata)
+    }
+
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_eq(self, other: Self) -> Option<bool>
+    where
+        T: Sized,
+    {
+        self.guaranteed_eq(other)
+    }
+
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_ne(self, other: Self) -> Option<bool>
+    where
+        T: Sized,
+    {
+        self.guaranteed_ne(other)
+    }
+}
 impl<T: ?Sized> Primitive for *mut T {}
-impl<T: ?Sized> Pointer for *mut T {}
+impl<T: ?Sized> Pointer for *mut T {
+    type Pointee = T;
+
+    fn addr(self) -> usize {
+        self.addr()
+    }
+
+    fn expose_provenance(self) -> usize {
+        self.expose_provenance()
+    }
+
+    fn from_exposed_provenance(addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        ptr::with_exposed_provenance_mut(addr)
+    }
+
+    fn align_offset(self, align: usize) -> usize
+    where
+        T: Sized,
+    {
+        self.align_offset(align)
+    }
+
+    unsafe fn read_volatile(self) -> T
+    where
+        T: Sized,
+    {
+        self.read_volatile()
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    fn to_raw_parts(self) -> (*const (), <T as ptr::Pointee>::Metadata) {
+        let (data, metadata) = self.to_raw_parts();
+        (data.cast_const(), metadata)
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    fn from_raw_parts(data: *const (), metadata: <T as ptr::Pointee>::Metadata) -> Self {
+        ptr::from_raw_parts_mut(data.cast_mut(), metadata)
+    }
+
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_eq(self, other: Self) -> Option<bool>
+    where
+        T: Sized,
+    {
+        self.guaranteed_eq(other)
+    }
+
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    fn guaranteed_ne(self, other: Self) -> Option<bool>
+    where
+        T: Sized,
+    {
+        self.guaranteed_ne(other)
+    }
+}
+impl<T: ?Sized> PointerMut for *mut T {
+    unsafe fn write_volatile(self, val: T)
+    where
+        T: Sized,
+    {
+        self.write_volatile(val)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expose_provenance() {
+        fn expose<P: Pointer>(p: P) -> usize {
+            p.expose_provenance()
+        }
+
+        let mut value = 42u32;
+        let addr = expose(&raw const value);
+        assert_eq!(addr, (&raw const value).addr());
+        let p: *const u32 = ptr::with_exposed_provenance(addr);
+        assert_eq!(unsafe { *p }, 42);
+
+        let addr = expose(&raw mut value);
+        let p: *mut u32 = ptr::with_exposed_provenance_mut(addr);
+        unsafe { *p = 7 };
+        assert_eq!(value, 7);
+
+        let slice: *const [u8] = &[1u8, 2, 3][..];
+        assert_eq!(expose(slice), slice.addr());
+    }
+
+    #[test]
+    fn test_from_exposed_provenance() {
+        fn roundtrip<P: Pointer>(p: P) -> P
+        where
+            P::Pointee: Sized,
+        {
+            P::from_exposed_provenance(p.expose_provenance())
+        }
+
+        let mut value = 42u64;
+        let p = roundtrip(&raw const value);
+        assert_eq!(unsafe { *p }, 42);
+
+        let p = roundtrip(&raw mut value);
+        unsafe { *p += 1 };
+        assert_eq!(value, 43);
+    }
+
+    #[cfg(feature = "ptr_metadata")]
+    #[test]
+    fn test_raw_parts() {
+        fn split<P: Pointer>(p: P) -> (*const (), <P::Pointee as ptr::Pointee>::Metadata) {
+            p.to_raw_parts()
+        }
+
+        let mut arr = [1u16, 2, 3, 4];
+        let slice: *const [u16] = &arr[..];
+        let (data, len) = split(slice);
+        assert_eq!(data, arr.as_ptr().cast());
+        assert_eq!(len, 4);
+        let rebuilt = <*const [u16]>::from_raw_parts(data, len - 1);
+        assert_eq!(unsafe { &*rebuilt }, &[1, 2, 3]);
+
+        let slice: *mut [u16] = &mut arr[1..];
+        let (data, len) = split(slice);
+        assert_eq!(len, 3);
+        let rebuilt = <*mut [u16]>::from_raw_parts(data, len);
+        unsafe { (*rebuilt)[0] = 5 };
+        assert_eq!(arr, [1, 5, 3, 4]);
+
+        let (_, ()) = split(&raw const arr);
+    }
+
+    #[cfg(feature = "const_raw_ptr_comparison")]
+    #[test]
+    fn test_guaranteed_eq() {
+        fn eq<P: Pointer>(a: P, b: P) -> Option<bool>
+        where
+            P::Pointee: Sized,
+        {
+            a.guaranteed_eq(b)
+        }
+
+        fn ne<P: Pointer>(a: P, b: P) -> Option<bool>
+        where
+            P::Pointee: Sized,
+        {
+            a.guaranteed_ne(b)
+        }
+
+        let mut a = 1u32;
+        let mut b = 2u32;
+        let pa = &raw const a;
+        let pb = &raw const b;
+        assert_eq!(eq(pa, pa), Some(true));
+        assert_eq!(ne(pa, pa), Some(false));
+        assert_eq!(eq(pa, pb), Some(false));
+        assert_eq!(ne(pa, pb), Some(true));
+
+        let pa = &raw mut a;
+        let pb = &raw mut b;
+        assert_eq!(eq(pa, pa), Some(true));
+        assert_eq!(ne(pa, pb), Some(true));
+    }
+
+    #[test]
+    fn test_align_offset() {
+        fn align_offset<P: Pointer>(p: P, align: usize) -> usize
+        where
+            P::Pointee: Sized,
+        {
+            p.align_offset(align)
+        }
+
+        #[repr(align(8))]
+        struct Aligned([u8; 16]);
+
+        let mut buf = Aligned([0; 16]);
+        let p = buf.0.as_ptr();
+        assert_eq!(align_offset(p, 2), 0);
+        assert_eq!(align_offset(p, 8), 0);
+        assert_eq!(align_offset(p.wrapping_add(1), 2), 1);
+        assert_eq!(align_offset(p.wrapping_add(3), 8), 5);
+
+        let p = buf.0.as_mut_ptr();
+        assert_eq!(align_offset(p, 4), 0);
+        assert_ne!(align_offset(p.wrapping_add(1), 2), 0);
+    }
+
+    #[test]
+    fn test_volatile() {
+        unsafe fn read<P: Pointer>(p: P) -> P::Pointee
+        where
+            P::Pointee: Sized,
+        {
+            unsafe { p.read_volatile() }
+        }
+
+        unsafe fn write<P: PointerMut>(p: P, val: P::Pointee)
+        where
+            P::Pointee: Sized,
+        {
+            unsafe { p.write_volatile(val) }
+        }
+
+        let mut register = 0x1234u32;
+        let p = &raw mut register;
+        unsafe {
+            assert_eq!(read(p), 0x1234);
+            write(p, 0xdead_beef);
+            assert_eq!(read(p), 0xdead_beef);
+            assert_eq!(read(p.cast_const()), 0xdead_beef);
+        }
+        assert_eq!(register, 0xdead_beef);
+    }
+}
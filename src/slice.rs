@@ -1,25 +1,657 @@
-use crate::primitive::Primitive;
+use core::{
+    any::type_name,
+    mem::{size_of, size_of_val},
+    slice::{IterMut, Split},
+};
+
+use crate::{
+    num::{Float, Number},
+    primitive::Primitive,
+};
 
 pub trait Slice: Primitive + AsRef<[Self::Item]> {
     type Item;
 
+    /// The size of a single item in bytes.
+    const ITEM_SIZE: usize;
+
     fn as_slice(&self) -> &[Self::Item];
+
+    /// Returns the name of the item type.
+    ///
+    /// See [`core::any::type_name`].
+    fn item_type_name() -> &'static str;
+
+    /// Returns the size of the slice in bytes.
+    fn byte_len(&self) -> usize;
+
+    /// See [`slice::split_at`].
+    fn split_at(&self, mid: usize) -> (&[Self::Item], &[Self::Item]);
+
+    /// See [`slice::contains`].
+    fn contains(&self, item: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq;
+
+    /// See [`slice::binary_search`].
+    fn binary_search(&self, item: &Self::Item) -> Result<usize, usize>
+    where
+        Self::Item: Ord;
+
+    /// Returns the index of the first item matching `pred`.
+    ///
+    /// See [`Iterator::position`].
+    fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&Self::Item) -> bool;
+
+    /// See [`slice::split`].
+    ///
+    /// For [`str`] this splits the bytes.
+    fn split<P>(&self, pred: P) -> Split<'_, Self::Item, P>
+    where
+        P: FnMut(&Self::Item) -> bool;
+
+    /// See [`slice::is_ascii`].
+    fn is_ascii(&self) -> bool
+    where
+        Self: AsRef<[u8]>;
+
+    /// Returns the Fletcher-16 checksum of the bytes.
+    fn fletcher16(&self) -> u16
+    where
+        Self: AsRef<[u8]>;
+
+    /// Returns the Adler-32 checksum of the bytes.
+    fn adler32(&self) -> u32
+    where
+        Self: AsRef<[u8]>;
+
+    /// Returns the sum of the items using compensated summation, which is
+    /// much more accurate than naive summation for long sequences.
+    ///
+    /// This uses Neumaier's variant of Kahan summation, which also handles
+    /// items that are larger in magnitude than the running sum.
+    fn kahan_sum(&self) -> Self::Item
+    where
+        Self::Item: Float,
+    {
+        // Provided here, as the `str` impl can't name the bound `u8: Float`.
+        kahan_sum(self.as_ref())
+    }
+
+    /// Returns the dot product of `self` and `other`, or `None` if their
+    /// lengths differ.
+    fn dot(&self, other: &[Self::Item]) -> Option<Self::Item>
+    where
+        Self::Item: Float,
+    {
+        let items = self.as_ref();
+        (items.len() == other.len()).then(|| dot(items, other))
+    }
+
+    /// Returns the Euclidean norm, i.e. the square root of the sum of the
+    /// squares of the items.
+    #[cfg(feature = "std")]
+    fn norm(&self) -> Self::Item
+    where
+        Self::Item: Float,
+    {
+        self.as_ref()
+            .iter()
+            .fold(Self::Item::ZERO, |mut acc, &v| {
+                let mut square = v;
+                square *= v;
+                acc += square;
+                acc
+            })
+            .sqrt()
+    }
+}
+
+/// A [`Slice`] whose items can be mutated in place.
+pub trait SliceMut: Slice + AsMut<[Self::Item]> {
+    fn as_mut_slice(&mut self) -> &mut [Self::Item];
+
+    /// See [`slice::iter_mut`].
+    fn iter_mut(&mut self) -> IterMut<'_, Self::Item>;
+
+    /// See [`slice::sort`].
+    #[cfg(feature = "alloc")]
+    fn sort(&mut self)
+    where
+        Self::Item: Ord;
+
+    /// See [`slice::sort_unstable`].
+    fn sort_unstable(&mut self)
+    where
+        Self::Item: Ord;
+
+    /// See [`slice::rotate_left`].
+    fn rotate_left(&mut self, mid: usize);
+
+    /// See [`slice::rotate_right`].
+    fn rotate_right(&mut self, k: usize);
+
+    /// See [`slice::swap`].
+    fn swap(&mut self, a: usize, b: usize);
+
+    /// See [`slice::make_ascii_uppercase`].
+    fn make_ascii_uppercase(&mut self)
+    where
+        Self: AsMut<[u8]>;
 }
 
 impl<T> Primitive for [T] {}
 impl<T> Slice for [T] {
     type Item = T;
 
+    const ITEM_SIZE: usize = size_of::<T>();
+
     fn as_slice(&self) -> &[Self::Item] {
         self
     }
+
+    fn item_type_name() -> &'static str {
+        type_name::<T>()
+    }
+
+    fn byte_len(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn split_at(&self, mid: usize) -> (&[Self::Item], &[Self::Item]) {
+        self.split_at(mid)
+    }
+
+    fn contains(&self, item: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        self.contains(item)
+    }
+
+    fn binary_search(&self, item: &Self::Item) -> Result<usize, usize>
+    where
+        Self::Item: Ord,
+    {
+        self.binary_search(item)
+    }
+
+    fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.iter().position(pred)
+    }
+
+    fn split<P>(&self, pred: P) -> Split<'_, Self::Item, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.split(pred)
+    }
+
+    fn is_ascii(&self) -> bool
+    where
+        Self: AsRef<[u8]>,
+    {
+        AsRef::<[u8]>::as_ref(self).is_ascii()
+    }
+
+    fn fletcher16(&self) -> u16
+    where
+        Self: AsRef<[u8]>,
+    {
+        fletcher16(self.as_ref())
+    }
+
+    fn adler32(&self) -> u32
+    where
+        Self: AsRef<[u8]>,
+    {
+        adler32(self.as_ref())
+    }
+}
+
+impl<T> SliceMut for [T] {
+    fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+        self
+    }
+
+    fn iter_mut(&mut self) -> IterMut<'_, Self::Item> {
+        self.iter_mut()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort(&mut self)
+    where
+        Self::Item: Ord,
+    {
+        self.sort()
+    }
+
+    fn sort_unstable(&mut self)
+    where
+        Self::Item: Ord,
+    {
+        self.sort_unstable()
+    }
+
+    fn rotate_left(&mut self, mid: usize) {
+        self.rotate_left(mid)
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        self.rotate_right(k)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.swap(a, b)
+    }
+
+    fn make_ascii_uppercase(&mut self)
+    where
+        Self: AsMut<[u8]>,
+    {
+        AsMut::<[u8]>::as_mut(self).make_ascii_uppercase()
+    }
 }
 
 impl Primitive for str {}
 impl Slice for str {
     type Item = u8;
 
+    const ITEM_SIZE: usize = 1;
+
     fn as_slice(&self) -> &[Self::Item] {
         self.as_bytes()
     }
+
+    fn item_type_name() -> &'static str {
+        type_name::<u8>()
+    }
+
+    fn byte_len(&self) -> usize {
+        self.len()
+    }
+
+    fn split_at(&self, mid: usize) -> (&[Self::Item], &[Self::Item]) {
+        self.as_bytes().split_at(mid)
+    }
+
+    fn contains(&self, item: &Self::Item) -> bool {
+        self.as_bytes().contains(item)
+    }
+
+    fn binary_search(&self, item: &Self::Item) -> Result<usize, usize> {
+        self.as_bytes().binary_search(item)
+    }
+
+    fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Slice::position(self.as_bytes(), pred)
+    }
+
+    fn split<P>(&self, pred: P) -> Split<'_, Self::Item, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.as_bytes().split(pred)
+    }
+
+    fn is_ascii(&self) -> bool {
+        self.is_ascii()
+    }
+
+    fn fletcher16(&self) -> u16 {
+        fletcher16(self.as_bytes())
+    }
+
+    fn adler32(&self) -> u32 {
+        adler32(self.as_bytes())
+    }
+}
+
+/// Sums the products of the pairs of items, stopping at the end of the
+/// shorter iterator.
+pub(crate) fn dot<'a, T: Number>(
+    a: impl IntoIterator<Item = &'a T>,
+    b: impl IntoIterator<Item = &'a T>,
+) -> T {
+    a.into_iter().zip(b).fold(T::ZERO, |mut acc, (&a, &b)| {
+        let mut product = a;
+        product *= b;
+        acc += product;
+        acc
+    })
+}
+
+fn kahan_sum<T: Float>(items: &[T]) -> T {
+    let mut sum = T::ZERO;
+    let mut compensation = T::ZERO;
+    for &v in items {
+        let mut t = sum;
+        t += v;
+        // Recover the low-order bits lost when adding the smaller operand,
+        // i.e. `(sum - t) + v` or `(v - t) + sum`.
+        let (mut lost, other) = if sum.abs() >= v.abs() {
+            (sum, v)
+        } else {
+            (v, sum)
+        };
+        lost -= t;
+        lost += other;
+        compensation += lost;
+        sum = t;
+    }
+    sum += compensation;
+    sum
+}
+
+fn fletcher16(bytes: &[u8]) -> u16 {
+    let (mut sum1, mut sum2) = (0u16, 0u16);
+    for &b in bytes {
+        sum1 = (sum1 + b as u16) % 255;
+        sum2 = (sum2 + sum1) % 255;
+    }
+    (sum2 << 8) | sum1
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_at() {
+        fn split_at<T: Slice + ?Sized>(v: &T, mid: usize) -> (&[T::Item], &[T::Item]) {
+            v.split_at(mid)
+        }
+
+        let v = [1, 2, 3, 4];
+        assert_eq!(split_at(&v[..], 2), (&[1, 2][..], &[3, 4][..]));
+        assert_eq!(split_at(&v[..], 0), (&[][..], &v[..]));
+        assert_eq!(split_at(&v[..], 4), (&v[..], &[][..]));
+        assert_eq!(split_at("abc", 1), (&b"a"[..], &b"bc"[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_out_of_bounds() {
+        Slice::split_at(&[1, 2][..], 3);
+    }
+
+    #[test]
+    fn test_contains() {
+        fn contains<T: Slice + ?Sized>(v: &T, item: T::Item) -> bool
+        where
+            T::Item: PartialEq,
+        {
+            v.contains(&item)
+        }
+
+        assert!(contains(&[1u8, 2, 3][..], 2));
+        assert!(!contains(&[1u8, 2, 3][..], 4));
+        assert!(contains("abc", b'c'));
+        assert!(!contains("abc", b'd'));
+    }
+
+    #[test]
+    fn test_binary_search() {
+        fn binary_search<T: Slice + ?Sized>(v: &T, item: T::Item) -> Result<usize, usize>
+        where
+            T::Item: Ord,
+        {
+            v.binary_search(&item)
+        }
+
+        let v = [1, 3, 5, 7];
+        assert_eq!(binary_search(&v[..], 5), Ok(2));
+        assert_eq!(binary_search(&v[..], 4), Err(2));
+        assert_eq!(binary_search(&v[..], 8), Err(4));
+        assert_eq!(binary_search("ace", b'c'), Ok(1));
+        assert_eq!(binary_search("ace", b'b'), Err(1));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        fn double<T: SliceMut<Item = i32> + ?Sized>(v: &mut T) {
+            for x in v.iter_mut() {
+                *x *= 2;
+            }
+        }
+
+        let mut v = [1, 2, 3];
+        double(&mut v[..]);
+        assert_eq!(v, [2, 4, 6]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sort() {
+        fn sort<T: SliceMut + ?Sized>(v: &mut T)
+        where
+            T::Item: Ord,
+        {
+            v.sort();
+        }
+
+        let mut v = [3, -1, 2, 0];
+        sort(&mut v[..]);
+        assert_eq!(v, [-1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        fn sort_unstable<T: SliceMut + ?Sized>(v: &mut T)
+        where
+            T::Item: Ord,
+        {
+            v.sort_unstable();
+        }
+
+        let mut v = [3, -1, 2, 0];
+        sort_unstable(&mut v[..]);
+        assert_eq!(v, [-1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        fn rotate_left<T: SliceMut + ?Sized>(v: &mut T, mid: usize) {
+            v.rotate_left(mid);
+        }
+
+        fn rotate_right<T: SliceMut + ?Sized>(v: &mut T, k: usize) {
+            v.rotate_right(k);
+        }
+
+        let mut v = [1, 2, 3, 4, 5];
+        rotate_left(&mut v[..], 2);
+        assert_eq!(v, [3, 4, 5, 1, 2]);
+        rotate_right(&mut v[..], 2);
+        assert_eq!(v, [1, 2, 3, 4, 5]);
+        rotate_left(&mut v[..], 5);
+        assert_eq!(v, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_byte_len() {
+        fn byte_len<T: Slice + ?Sized>(v: &T) -> (usize, usize) {
+            (T::ITEM_SIZE, v.byte_len())
+        }
+
+        assert_eq!(byte_len(&[1u32, 2, 3][..]), (4, 12));
+        assert_eq!(byte_len(&[0u64; 0][..]), (8, 0));
+        assert_eq!(byte_len(&[(); 5][..]), (0, 0));
+        assert_eq!(byte_len("héllo"), (1, 6));
+    }
+
+    #[test]
+    fn test_position() {
+        fn first_even<T: Slice<Item = i32> + ?Sized>(v: &T) -> Option<usize> {
+            v.position(|x| x % 2 == 0)
+        }
+
+        assert_eq!(first_even(&[1, 3, 4, 6][..]), Some(2));
+        assert_eq!(first_even(&[1, 3, 5][..]), None);
+        assert_eq!(first_even(&[][..]), None);
+        assert_eq!(Slice::position("a,b", |&b| b == b','), Some(1));
+    }
+
+    #[test]
+    fn test_is_ascii() {
+        fn is_ascii<T: Slice + AsRef<[u8]> + ?Sized>(v: &T) -> bool {
+            v.is_ascii()
+        }
+
+        assert!(is_ascii("hello"));
+        assert!(!is_ascii("héllo"));
+        assert!(is_ascii(&b"hello"[..]));
+        assert!(!is_ascii(&[0x80u8][..]));
+        assert!(is_ascii(""));
+    }
+
+    #[test]
+    fn test_make_ascii_uppercase() {
+        fn make_ascii_uppercase<T: SliceMut + AsMut<[u8]> + ?Sized>(v: &mut T) {
+            v.make_ascii_uppercase();
+        }
+
+        let mut v = *b"hello";
+        make_ascii_uppercase(&mut v[..]);
+        assert_eq!(&v, b"HELLO");
+
+        let mut v: [u8; 6] = "héllo".as_bytes().try_into().unwrap();
+        make_ascii_uppercase(&mut v[..]);
+        assert_eq!(core::str::from_utf8(&v), Ok("HéLLO"));
+    }
+
+    #[test]
+    fn test_item_type_name() {
+        fn item_type_name<T: Slice + ?Sized>(_: &T) -> &'static str {
+            T::item_type_name()
+        }
+
+        assert!(item_type_name(&[0u16; 4][..]).contains("u16"));
+        assert!(item_type_name("abc").contains("u8"));
+    }
+
+    #[test]
+    fn test_swap() {
+        fn swap<T: SliceMut + ?Sized>(v: &mut T, a: usize, b: usize) {
+            v.swap(a, b);
+        }
+
+        let mut v = [1u8, 2, 3];
+        swap(&mut v[..], 0, 2);
+        assert_eq!(v, [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
+        SliceMut::swap(&mut [1u8, 2, 3][..], 3, 0);
+    }
+
+    #[test]
+    fn test_checksums() {
+        fn fletcher16<T: Slice + AsRef<[u8]> + ?Sized>(v: &T) -> u16 {
+            v.fletcher16()
+        }
+
+        fn adler32<T: Slice + AsRef<[u8]> + ?Sized>(v: &T) -> u32 {
+            v.adler32()
+        }
+
+        assert_eq!(fletcher16("abcde"), 0xc8f0);
+        assert_eq!(fletcher16("abcdef"), 0x2057);
+        assert_eq!(fletcher16(&b"abcdefgh"[..]), 0x0627);
+        assert_eq!(fletcher16(""), 0);
+
+        assert_eq!(adler32("Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(&b"abcde"[..]), 0x05c8_01f0);
+        assert_eq!(adler32(""), 1);
+
+        // 0xff is congruent to 0 modulo 255.
+        assert_eq!(fletcher16(&[0xffu8; 1000][..]), 0);
+    }
+
+    #[test]
+    fn test_split() {
+        fn split<T: Slice + ?Sized, P: FnMut(&T::Item) -> bool>(
+            v: &T,
+            pred: P,
+        ) -> Split<'_, T::Item, P> {
+            v.split(pred)
+        }
+
+        let v = [1, 0, 2, 0, 3];
+        assert!(split(&v[..], |&x| x == 0).eq([&[1][..], &[2], &[3]]));
+        assert!(split(&[0, 1][..], |&x| x == 0).eq([&[][..], &[1]]));
+        assert!(split("a,bc", |&b| b == b',').eq([&b"a"[..], b"bc"]));
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        fn kahan_sum<T: Slice + ?Sized>(v: &T) -> T::Item
+        where
+            T::Item: Float,
+        {
+            v.kahan_sum()
+        }
+
+        let v = [1e16, 1.0, -1e16];
+        assert_eq!(v.iter().sum::<f64>(), 0.0);
+        assert_eq!(kahan_sum(&v[..]), 1.0);
+
+        let v = [0.1f32; 10_000];
+        assert_eq!(kahan_sum(&v[..]), 1000.0);
+        assert_ne!(v.iter().sum::<f32>(), 1000.0);
+
+        assert_eq!(kahan_sum::<[f64]>(&[]), 0.0);
+        assert!(kahan_sum(&[1.0, f64::NAN][..]).is_nan());
+    }
+
+    #[test]
+    fn test_dot() {
+        fn dot<T: Slice + ?Sized>(a: &T, b: &[T::Item]) -> Option<T::Item>
+        where
+            T::Item: Float,
+        {
+            a.dot(b)
+        }
+
+        let v = [3.0, 4.0];
+        assert_eq!(dot(&v[..], &v), Some(25.0));
+        assert_eq!(dot(&[1.0f32, 2.0, 3.0][..], &[4.0, -5.0, 6.0]), Some(12.0));
+        assert_eq!(dot(&v[..], &[1.0]), None);
+        assert_eq!(dot::<[f64]>(&[], &[]), Some(0.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_norm() {
+        fn norm<T: Slice + ?Sized>(v: &T) -> T::Item
+        where
+            T::Item: Float,
+        {
+            v.norm()
+        }
+
+        assert_eq!(norm(&[3.0, 4.0][..]), 5.0);
+        assert_eq!(norm(&[-2.0f32][..]), 2.0);
+        assert_eq!(norm::<[f64]>(&[]), 0.0);
+    }
 }
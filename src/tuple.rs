@@ -1,7 +1,40 @@
-use crate::{array::Array, primitive::Primitive};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{array::Array, fun::FunctionPointer, primitive::Primitive};
 
 pub trait Tuple: Primitive {
     const N: usize;
+
+    /// [`N`](Tuple::N) as a type-level number.
+    ///
+    /// This allows constraining the arity of a tuple in a bound:
+    ///
+    /// ```
+    /// use std_traits::tuple::Tuple;
+    ///
+    /// fn pair<T: Tuple<Arity = typenum::U2>>(_: T) {}
+    ///
+    /// pair((1, "a"));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use std_traits::tuple::Tuple;
+    ///
+    /// fn pair<T: Tuple<Arity = typenum::U2>>(_: T) {}
+    ///
+    /// pair((1, "a", 2.0));
+    /// ```
+    #[cfg(feature = "typenum")]
+    type Arity: typenum::Unsigned;
+
+    /// Calls `f` with the items of `self` as arguments.
+    ///
+    /// See [`FunctionPointer::call`].
+    fn apply_to<F>(self, f: F) -> F::Return
+    where
+        Self: Sized,
+        F: FunctionPointer<Args = Self>;
 }
 
 pub trait HomogeneousTuple<Item>: Tuple {
@@ -11,11 +44,56 @@ pub trait HomogeneousTuple<Item>: Tuple {
     where
         Self: Sized;
     fn from_array(array: Self::Array) -> Self;
+
+    /// Creates a tuple where each item is returned by `f` called with its
+    /// index.
+    ///
+    /// See [`Array::from_fn`].
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Item;
+
+    /// Copies `slice` into a tuple, returning `None` if its length is not
+    /// [`N`](Tuple::N).
+    fn try_from_slice(slice: &[Item]) -> Option<Self>
+    where
+        Self: Sized,
+        Item: Copy;
+
+    /// Moves the items of `vec` into a tuple, returning `vec` back if its
+    /// length is not [`N`](Tuple::N).
+    ///
+    /// This is the tuple equivalent of the [`TryFrom<Vec<T>>`] implementation
+    /// for arrays. The orphan rules prevent implementing [`TryFrom`] for
+    /// tuples here, as neither the trait nor the tuple types are local.
+    #[cfg(feature = "alloc")]
+    fn try_from_vec(vec: Vec<Item>) -> Result<Self, Vec<Item>>
+    where
+        Self: Sized;
+}
+
+/// Converts a tuple whose elements all have the same type into an array.
+///
+/// The element type is inferred, so tuples with mixed element types fail to
+/// compile.
+pub fn tuple_to_array<T, Tup: HomogeneousTuple<T>>(tuple: Tup) -> Tup::Array {
+    tuple.into_array()
 }
 
 impl Primitive for () {}
 impl Tuple for () {
     const N: usize = 0;
+
+    #[cfg(feature = "typenum")]
+    type Arity = typenum::U0;
+
+    fn apply_to<F>(self, f: F) -> F::Return
+    where
+        Self: Sized,
+        F: FunctionPointer<Args = Self>,
+    {
+        f.call(self)
+    }
 }
 impl<T> HomogeneousTuple<T> for () {
     type Array = [T; 0];
@@ -31,6 +109,25 @@ impl<T> HomogeneousTuple<T> for () {
         #[allow(clippy::unused_unit)]
         ()
     }
+
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::from_array(Array::from_fn(f))
+    }
+
+    fn try_from_slice(slice: &[T]) -> Option<Self>
+    where
+        T: Copy,
+    {
+        Array::try_from_slice(slice).map(Self::from_array)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        Self::Array::try_from(vec).map(Self::from_array)
+    }
 }
 
 #[cfg_attr(docsrs, doc(fake_variadic))]
@@ -47,6 +144,17 @@ impl<T1: ?Sized> Primitive for (T1,) {}
 )]
 impl<T1: ?Sized> Tuple for (T1,) {
     const N: usize = 1;
+
+    #[cfg(feature = "typenum")]
+    type Arity = typenum::U1;
+
+    fn apply_to<F>(self, f: F) -> F::Return
+    where
+        Self: Sized,
+        F: FunctionPointer<Args = Self>,
+    {
+        f.call(self)
+    }
 }
 
 #[cfg_attr(docsrs, doc(fake_variadic))]
@@ -67,6 +175,25 @@ impl<T> HomogeneousTuple<T> for (T,) {
     fn from_array(array: Self::Array) -> Self {
         array.into()
     }
+
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::from_array(Array::from_fn(f))
+    }
+
+    fn try_from_slice(slice: &[T]) -> Option<Self>
+    where
+        T: Copy,
+    {
+        Array::try_from_slice(slice).map(Self::from_array)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        Self::Array::try_from(vec).map(Self::from_array)
+    }
 }
 
 macro_rules! replace_expr {
@@ -89,12 +216,23 @@ macro_rules! homogeneous_tuple {
 }
 
 macro_rules! impl_tuple {
-    ($n:expr => $($types:tt $i:tt),*; $last:tt $last_i:tt) => {
+    ($n:expr, $arity:ident => $($types:tt $i:tt),*; $last:tt $last_i:tt) => {
         #[cfg_attr(docsrs, doc(hidden))]
         impl<$($types,)* $last: ?Sized> Primitive for ($($types,)* $last,) {}
         #[cfg_attr(docsrs, doc(hidden))]
         impl<$($types,)* $last: ?Sized> Tuple for ($($types,)* $last,) {
             const N: usize = $n;
+
+            #[cfg(feature = "typenum")]
+            type Arity = typenum::$arity;
+
+            fn apply_to<F>(self, f: F) -> F::Return
+            where
+                Self: Sized,
+                F: FunctionPointer<Args = Self>,
+            {
+                f.call(self)
+            }
         }
         #[cfg_attr(docsrs, doc(hidden))]
         impl<T> HomogeneousTuple<T> for homogeneous_tuple!($($types,)* $last) {
@@ -110,25 +248,45 @@ macro_rules! impl_tuple {
             fn from_array(array: Self::Array) -> Self {
                 array.into()
             }
+
+            fn from_fn<F>(f: F) -> Self
+            where
+                F: FnMut(usize) -> T,
+            {
+                Self::from_array(Array::from_fn(f))
+            }
+
+            fn try_from_slice(slice: &[T]) -> Option<Self>
+            where
+                T: Copy,
+            {
+                Array::try_from_slice(slice).map(Self::from_array)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+                Self::Array::try_from(vec).map(Self::from_array)
+            }
         }
     }
 }
 
 /*
 for n in range(2, 13):
-    print(f"impl_tuple!({n} => {', '.join(f'T{i} {i - 1}' for i in range(1, n))}; T{n} {n - 1});")
+    types = ', '.join(f'T{i} {i - 1}' for i in range(1, n))
+    print(f"impl_tuple!({n}, U{n} => {types}; T{n} {n - 1});")
 */
-impl_tuple!(2 => T1 0; T2 1);
-impl_tuple!(3 => T1 0, T2 1; T3 2);
-impl_tuple!(4 => T1 0, T2 1, T3 2; T4 3);
-impl_tuple!(5 => T1 0, T2 1, T3 2, T4 3; T5 4);
-impl_tuple!(6 => T1 0, T2 1, T3 2, T4 3, T5 4; T6 5);
-impl_tuple!(7 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5; T7 6);
-impl_tuple!(8 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6; T8 7);
-impl_tuple!(9 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7; T9 8);
-impl_tuple!(10 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8; T10 9);
-impl_tuple!(11 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9; T11 10);
-impl_tuple!(12 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10; T12 11);
+impl_tuple!(2, U2 => T1 0; T2 1);
+impl_tuple!(3, U3 => T1 0, T2 1; T3 2);
+impl_tuple!(4, U4 => T1 0, T2 1, T3 2; T4 3);
+impl_tuple!(5, U5 => T1 0, T2 1, T3 2, T4 3; T5 4);
+impl_tuple!(6, U6 => T1 0, T2 1, T3 2, T4 3, T5 4; T6 5);
+impl_tuple!(7, U7 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5; T7 6);
+impl_tuple!(8, U8 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6; T8 7);
+impl_tuple!(9, U9 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7; T9 8);
+impl_tuple!(10, U10 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8; T10 9);
+impl_tuple!(11, U11 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9; T11 10);
+impl_tuple!(12, U12 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10; T12 11);
 
 #[cfg(test)]
 mod test {
@@ -163,6 +321,14 @@ mod test {
         let _: [u8; 0] = tuple.into_array();
     }
 
+    #[test]
+    fn test_tuple_to_array() {
+        assert_eq!(tuple_to_array((1u8, 2u8, 3u8)), [1, 2, 3]);
+        assert_eq!(tuple_to_array((String::from("a"),)), [String::from("a")]);
+        let empty: [u8; 0] = tuple_to_array(());
+        assert_eq!(empty, []);
+    }
+
     #[test]
     fn test_from_array_1() {
         test_from_array!(String);
@@ -185,4 +351,69 @@ mod test {
             String
         );
     }
+
+    #[cfg(feature = "typenum")]
+    #[test]
+    fn test_arity() {
+        use typenum::Unsigned;
+
+        fn arity<T: Tuple>(_: T) -> usize {
+            assert_eq!(T::Arity::USIZE, T::N);
+            T::Arity::USIZE
+        }
+
+        assert_eq!(arity(()), 0);
+        assert_eq!(arity((1,)), 1);
+        assert_eq!(arity((1, "a")), 2);
+        assert_eq!(arity((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)), 12);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        assert_eq!(<(u8, u8, u8)>::from_fn(|i| i as u8), (0, 1, 2));
+        assert_eq!(
+            <(String,)>::from_fn(|i| format!("{i}")),
+            (String::from("0"),)
+        );
+        #[allow(clippy::let_unit_value)]
+        let () = <()>::from_fn(|_| -> u8 { unreachable!() });
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let v = [1u8, 2, 3];
+        assert_eq!(<(u8, u8, u8)>::try_from_slice(&v), Some((1, 2, 3)));
+        assert_eq!(<(u8, u8, u8)>::try_from_slice(&v[..2]), None);
+        assert_eq!(<(u8,)>::try_from_slice(&v[2..]), Some((3,)));
+        assert_eq!(<()>::try_from_slice(&v[..0]), Some(()));
+        assert_eq!(<()>::try_from_slice(&v), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_try_from_vec() {
+        assert_eq!(<(u8, u8, u8)>::try_from_vec(vec![1, 2, 3]), Ok((1, 2, 3)));
+        assert_eq!(<(u8, u8, u8)>::try_from_vec(vec![1, 2]), Err(vec![1, 2]));
+        assert_eq!(
+            <(String,)>::try_from_vec(vec!["a".to_string()]),
+            Ok(("a".to_string(),))
+        );
+        assert_eq!(<()>::try_from_vec(Vec::<u8>::new()), Ok(()));
+        assert_eq!(<()>::try_from_vec(vec![1u8]), Err(vec![1]));
+    }
+
+    #[test]
+    fn test_apply_to() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        fn answer() -> u8 {
+            42
+        }
+
+        assert_eq!((2, 3).apply_to(add as fn(_, _) -> _), 5);
+        assert_eq!(().apply_to(answer as fn() -> u8), 42);
+        assert_eq!(("x",).apply_to(str::len as fn(&'static str) -> usize), 1);
+    }
 }